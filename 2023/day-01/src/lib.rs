@@ -0,0 +1,27 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// Each line of the calibration document
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
@@ -14,11 +14,11 @@ fn get_nums_from_str(line: &str) -> u64 {
         let lc = line.as_bytes()[left] as char;
         let cc = line.as_bytes()[right] as char;
 
-        if lc.is_digit(10) && first_digit.is_none() {
+        if lc.is_ascii_digit() && first_digit.is_none() {
             first_digit = Some(lc.to_digit(10).unwrap());
         }
 
-        if cc.is_digit(10) && last_digit.is_none() {
+        if cc.is_ascii_digit() && last_digit.is_none() {
             last_digit = Some(cc.to_digit(10).unwrap());
         }
 
@@ -34,47 +34,28 @@ fn get_nums_from_str(line: &str) -> u64 {
         panic!("Invalid string encountered: {}", line);
     }
 
-    println!(
-        "Line: {} | {}{}",
-        line,
-        first_digit.unwrap(),
-        last_digit.unwrap()
-    );
     format!("{}{}", first_digit.unwrap(), last_digit.unwrap())
         .parse::<u64>()
         .unwrap()
 }
 
-fn get_hehe(str: String) -> u128 {
-    let mut total = 0;
-
-    for line in str.lines() {
-        total += get_nums_from_str(line) as u128;
-    }
-
-    total
-}
-
-fn main() {
-    let total = get_hehe(std::fs::read_to_string("./data/input.txt").unwrap());
-
-    println!("Total: {}", total);
+pub fn solve(lines: &[String]) -> u64 {
+    lines.iter().map(|line| get_nums_from_str(line)).sum()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day01;
+    use utils::Solution;
 
     #[test]
     fn given_test() {
         let lines = r#"1abc2
 pqr3stu8vwx
 a1b2c3d4e5f
-three8seven
-four24qphdrxfsf
-9sevenvlttm
 treb7uchet"#;
 
-        assert_eq!(get_hehe(lines.to_string()), 142 + 88 + 99 + 24);
+        assert_eq!(solve(&Day01::parse(lines)), 142);
     }
 }
@@ -29,7 +29,7 @@ fn process_line(line: &str) -> u32 {
     });
     let first = it.next().expect("should be a number");
 
-    match it.last() {
+    match it.next_back() {
         Some(num) => format!("{first}{num}"),
         None => format!("{first}{first}"),
     }
@@ -37,19 +37,15 @@ fn process_line(line: &str) -> u32 {
     .expect("Should be a valid number")
 }
 
-fn main() {
-    let total = std::fs::read_to_string("./data/input.txt")
-        .unwrap()
-        .lines()
-        .map(process_line)
-        .sum::<u32>();
-
-    println!("{total}");
+pub fn solve(lines: &[String]) -> u32 {
+    lines.iter().map(|line| process_line(line)).sum()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day01;
+    use utils::Solution;
 
     #[test]
     fn hehe() {
@@ -62,6 +58,6 @@ zoneight234
 7pqrstsixteen
 "#;
 
-        assert_eq!(lines.lines().map(process_line).sum::<u32>(), 281);
+        assert_eq!(solve(&Day01::parse(lines)), 281);
     }
 }
@@ -0,0 +1,77 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// The cubes shown in one handful, or the most of each colour seen.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Cubes {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl From<&str> for Cubes {
+    fn from(value: &str) -> Self {
+        let mut cubes = Self::default();
+
+        for str in value.split(",") {
+            let (num, color) = str.trim().split_once(' ').unwrap();
+            let num = num.parse::<u32>().unwrap();
+
+            match color.to_lowercase().as_str() {
+                "blue" => cubes.blue = num,
+                "red" => cubes.red = num,
+                "green" => cubes.green = num,
+                _ => panic!("Invalid color encountered {color}"),
+            };
+        }
+
+        cubes
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: u32,
+    pub sets: Vec<Cubes>,
+}
+
+impl From<&str> for Game {
+    fn from(value: &str) -> Self {
+        let (game_id, cube_sets) = value.split_once(':').unwrap();
+        let id = game_id
+            .split(' ')
+            .next_back()
+            .unwrap()
+            .parse::<u32>()
+            .unwrap();
+
+        Self {
+            id,
+            sets: cube_sets.split(';').map(Cubes::from).collect(),
+        }
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<Game>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(Game::from)
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{Cubes, Game};
+
+const BAG: Cubes = Cubes {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+fn is_set_valid(set: &Cubes) -> bool {
+    set.red <= BAG.red && set.green <= BAG.green && set.blue <= BAG.blue
+}
+
+pub fn solve(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .filter(|game| game.sets.iter().all(is_set_valid))
+        .map(|game| game.id)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use utils::Solution;
+
+    #[test]
+    fn test_it() {
+        let input = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
+
+        assert_eq!(8, solve(&Day02::parse(input)));
+    }
+}
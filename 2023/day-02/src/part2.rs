@@ -0,0 +1,45 @@
+use crate::{Cubes, Game};
+
+/// The fewest cubes of each colour the game could have been played with.
+fn fewest_cubes(game: &Game) -> Cubes {
+    game.sets.iter().fold(Cubes::default(), |most, set| Cubes {
+        red: most.red.max(set.red),
+        green: most.green.max(set.green),
+        blue: most.blue.max(set.blue),
+    })
+}
+
+pub fn solve(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .map(fewest_cubes)
+        .map(|cubes| cubes.red * cubes.green * cubes.blue)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use utils::Solution;
+
+    #[test]
+    fn power() {
+        let input = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
+        let games = Day02::parse(input);
+
+        assert_eq!(
+            fewest_cubes(&games[0]),
+            Cubes {
+                red: 4,
+                green: 2,
+                blue: 6
+            }
+        );
+        assert_eq!(solve(&games), 2286);
+    }
+}
@@ -0,0 +1,82 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// A number in the schematic, spanning columns `start..end` of `row`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Number {
+    pub value: u32,
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Number {
+    /// Whether the cell at `row`, `col` touches the number, diagonals
+    /// included.
+    pub fn is_adjacent(&self, row: usize, col: usize) -> bool {
+        row + 1 >= self.row && row <= self.row + 1 && col + 1 >= self.start && col <= self.end
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Symbol {
+    pub ch: char,
+    pub row: usize,
+    pub col: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct Schematic {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+}
+
+impl From<&str> for Schematic {
+    fn from(value: &str) -> Self {
+        let mut schematic = Self::default();
+
+        for (row, line) in value.lines().enumerate() {
+            let mut start = None;
+            for (col, ch) in line.chars().chain(std::iter::once('.')).enumerate() {
+                if ch.is_ascii_digit() {
+                    start.get_or_insert(col);
+                    continue;
+                }
+
+                if let Some(start) = start.take() {
+                    schematic.numbers.push(Number {
+                        value: line[start..col].parse().unwrap(),
+                        row,
+                        start,
+                        end: col,
+                    });
+                }
+                if ch != '.' {
+                    schematic.symbols.push(Symbol { ch, row, col });
+                }
+            }
+        }
+
+        schematic
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Schematic;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Schematic::from(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
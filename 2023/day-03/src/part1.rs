@@ -0,0 +1,46 @@
+use crate::Schematic;
+
+pub fn solve(schematic: &Schematic) -> u32 {
+    schematic
+        .numbers
+        .iter()
+        .filter(|num| {
+            schematic
+                .symbols
+                .iter()
+                .any(|symbol| num.is_adjacent(symbol.row, symbol.col))
+        })
+        .map(|num| num.value)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day03;
+    use utils::Solution;
+
+    #[test]
+    fn end() {
+        let input = r#"...463
+..*..."#;
+
+        assert_eq!(463, solve(&Day03::parse(input)));
+    }
+
+    #[test]
+    fn example() {
+        let input = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#;
+
+        assert_eq!(4361, solve(&Day03::parse(input)));
+    }
+}
@@ -0,0 +1,46 @@
+use crate::Schematic;
+
+/// Gears are `*` symbols touching exactly two numbers, whose product is the
+/// gear ratio.
+pub fn solve(schematic: &Schematic) -> u32 {
+    schematic
+        .symbols
+        .iter()
+        .filter(|symbol| symbol.ch == '*')
+        .filter_map(|symbol| {
+            let numbers = schematic
+                .numbers
+                .iter()
+                .filter(|num| num.is_adjacent(symbol.row, symbol.col))
+                .collect::<Vec<_>>();
+
+            match numbers[..] {
+                [first, second] => Some(first.value * second.value),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day03;
+    use utils::Solution;
+
+    #[test]
+    fn end() {
+        let input = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#;
+
+        assert_eq!(467835, solve(&Day03::parse(input)));
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug, Clone)]
+pub struct ScratchCard {
+    pub id: usize,
+    pub winning_numbers: HashSet<u32>,
+    pub scratched_numbers: HashSet<u32>,
+}
+
+impl From<&str> for ScratchCard {
+    fn from(value: &str) -> Self {
+        let (id_str, rest) = value.split_once(':').unwrap();
+        let id = id_str
+            .split_once(' ')
+            .unwrap()
+            .1
+            .trim()
+            .parse::<usize>()
+            .unwrap();
+
+        let (win_num_str, scratch_num_str) = rest.split_once('|').unwrap();
+        let winning_numbers = win_num_str
+            .trim()
+            .split(' ')
+            .filter_map(|s| s.parse::<u32>().ok())
+            .collect::<HashSet<u32>>();
+        let scratched_numbers = scratch_num_str
+            .trim()
+            .split(' ')
+            .filter_map(|s| s.parse::<u32>().ok())
+            .collect::<HashSet<u32>>();
+
+        Self {
+            id,
+            winning_numbers,
+            scratched_numbers,
+        }
+    }
+}
+
+impl ScratchCard {
+    /// How many of the scratched numbers are winning numbers.
+    pub fn matching_numbers(&self) -> usize {
+        self.winning_numbers
+            .intersection(&self.scratched_numbers)
+            .count()
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Vec<ScratchCard>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(ScratchCard::from)
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
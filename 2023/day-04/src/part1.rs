@@ -0,0 +1,74 @@
+use crate::ScratchCard;
+
+/// One point for the first match, doubled for every match after it.
+fn calc_points(card: &ScratchCard) -> u32 {
+    match card.matching_numbers() {
+        0 => 0,
+        matches => 1 << (matches - 1),
+    }
+}
+
+pub fn solve(cards: &[ScratchCard]) -> u32 {
+    cards.iter().map(calc_points).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day04;
+    use utils::Solution;
+
+    fn funky_one(input: &[u8]) -> usize {
+        let col = input.iter().position(|&b| b == b':').unwrap();
+        let sep = input.iter().position(|&b| b == b'|').unwrap();
+
+        let total = input
+            .split(|&b| b == b'\n')
+            .filter(|game| !game.is_empty())
+            .map(|game| {
+                let win_seq = &game[col + 1..sep];
+                let win_count = game[sep + 1..]
+                    .chunks_exact(3)
+                    .map(|n| &n[1..])
+                    .filter(|n| win_seq.chunks_exact(3).map(|n| &n[1..]).any(|c| &c == n))
+                    .count() as u32;
+                2usize.pow(win_count) >> 1
+            })
+            .sum::<usize>();
+
+        total
+    }
+
+    #[test]
+    fn sample() {
+        let input = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
+
+        assert_eq!(13, solve(&Day04::parse(input)));
+    }
+
+    #[test]
+    fn funky() {
+        let input = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
+
+        assert_eq!(funky_one(input.as_bytes()), 13);
+
+        // The real input is only compared on when there is one
+        let input = match utils::read_input(2023, 4, &utils::InputSource::Default) {
+            Ok(input) => input,
+            Err(utils::InputError::Missing(_)) => return,
+            Err(err) => panic!("{err}"),
+        };
+        let total = funky_one(input.as_bytes());
+        assert_eq!(solve(&Day04::parse(&input)), total as u32);
+    }
+}
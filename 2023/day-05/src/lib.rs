@@ -0,0 +1,374 @@
+use utils::{Answer, IntervalSet, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// A contiguous run of values, `start..=end`, moved as a block so that
+/// `start` lands on `destination`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Piece {
+    start: u64,
+    end: u64,
+    destination: u64,
+}
+
+impl Piece {
+    fn get(&self, value: u64) -> u64 {
+        self.destination + (value - self.start)
+    }
+
+    fn image(&self) -> (u64, u64) {
+        (self.destination, self.get(self.end))
+    }
+}
+
+/// The `length` values from `start`, `None` when there are none. Taking one
+/// off the length first keeps ranges ending at `u64::MAX` from overflowing.
+fn span(start: u64, length: u64) -> Option<(u64, u64)> {
+    length.checked_sub(1).map(|last| (start, start + last))
+}
+
+/// One of the almanac's maps. Every `u64` is covered by exactly one piece,
+/// with values outside the listed ranges mapping to themselves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    pieces: Vec<Piece>,
+}
+
+impl PiecewiseMap {
+    /// Builds a map from `destination source length` entries, which must
+    /// not overlap.
+    pub fn new(entries: impl IntoIterator<Item = (u64, u64, u64)>) -> Self {
+        let mut listed = entries
+            .into_iter()
+            .filter_map(|(destination, start, length)| {
+                let (start, end) = span(start, length)?;
+                Some(Piece {
+                    start,
+                    end,
+                    destination,
+                })
+            })
+            .collect::<Vec<_>>();
+        listed.sort_by_key(|piece| piece.start);
+
+        // Fill the gaps with pieces that leave values where they are
+        let mut pieces = Vec::with_capacity(listed.len() * 2 + 1);
+        let mut next = Some(0);
+        for piece in listed {
+            let from = next.expect("pieces to not overlap");
+            assert!(piece.start >= from, "pieces to not overlap");
+            if piece.start > from {
+                pieces.push(Piece {
+                    start: from,
+                    end: piece.start - 1,
+                    destination: from,
+                });
+            }
+            pieces.push(piece);
+            next = piece.end.checked_add(1);
+        }
+        if let Some(from) = next {
+            pieces.push(Piece {
+                start: from,
+                end: u64::MAX,
+                destination: from,
+            });
+        }
+
+        Self { pieces }
+    }
+
+    pub fn get(&self, value: u64) -> u64 {
+        let index = self.pieces.partition_point(|piece| piece.end < value);
+        self.pieces[index].get(value)
+    }
+
+    /// The map that applies `self` and then `other`.
+    pub fn then(&self, other: &PiecewiseMap) -> PiecewiseMap {
+        let mut pieces = Vec::new();
+
+        for piece in &self.pieces {
+            let (image_start, image_end) = piece.image();
+            let first = other.pieces.partition_point(|next| next.end < image_start);
+
+            // Split the piece wherever its image crosses into another of
+            // `other`'s pieces
+            for next in other.pieces[first..]
+                .iter()
+                .take_while(|next| next.start <= image_end)
+            {
+                let from = image_start.max(next.start);
+                let to = image_end.min(next.end);
+                pieces.push(Piece {
+                    start: piece.start + (from - image_start),
+                    end: piece.start + (to - image_start),
+                    destination: next.get(from),
+                });
+            }
+        }
+
+        Self { pieces }
+    }
+
+    /// The map taking each output back to its input, `None` when two
+    /// inputs share an output.
+    pub fn inverse(&self) -> Option<PiecewiseMap> {
+        let mut pieces = self
+            .pieces
+            .iter()
+            .map(|piece| {
+                let (start, end) = piece.image();
+                Piece {
+                    start,
+                    end,
+                    destination: piece.start,
+                }
+            })
+            .collect::<Vec<_>>();
+        pieces.sort_by_key(|piece| piece.start);
+
+        // Images of a map covering every value can only cover every value
+        // once if they line up end to end
+        let covered = pieces.first().is_some_and(|piece| piece.start == 0)
+            && pieces.last().is_some_and(|piece| piece.end == u64::MAX)
+            && pieces
+                .windows(2)
+                .all(|pair| pair[0].end.checked_add(1) == Some(pair[1].start));
+
+        covered.then_some(Self { pieces })
+    }
+
+    /// Maps every value in `values`, splitting ranges that straddle pieces.
+    pub fn apply(&self, values: &IntervalSet<u64>) -> IntervalSet<u64> {
+        values.map_pieces(
+            &self
+                .pieces
+                .iter()
+                .map(|piece| (piece.start..=piece.end, piece.destination))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// The lowest output of any value in `values`. Walks the outputs in
+    /// ascending order and stops at the first one with an input in
+    /// `values`, rather than mapping all of them.
+    pub fn min_output(&self, values: &IntervalSet<u64>) -> Option<u64> {
+        let Some(inverse) = self.inverse() else {
+            return self.apply(values).iter().next().map(|range| *range.start());
+        };
+
+        inverse.pieces.iter().find_map(|piece| {
+            let inputs = IntervalSet::from_iter([piece.destination..=piece.get(piece.end)]);
+            let lowest = values.intersection(&inputs).iter().next()?;
+            Some(piece.start + (lowest.start() - piece.destination))
+        })
+    }
+}
+
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub maps: Vec<PiecewiseMap>,
+}
+
+impl From<&str> for Almanac {
+    fn from(value: &str) -> Self {
+        let mut sections = value.split("\n\n");
+        let seeds = sections
+            .next()
+            .expect("a seeds line")
+            .trim_start_matches("seeds:")
+            .split_whitespace()
+            .map(|seed| seed.parse::<u64>().expect("a valid seed"))
+            .collect();
+
+        let maps = sections
+            .map(|section| {
+                PiecewiseMap::new(section.lines().skip(1).map(|line| {
+                    let mut nums = line
+                        .split_whitespace()
+                        .map(|num| num.parse::<u64>().expect("a valid num"));
+                    let mut next = || nums.next().expect("three numbers per entry");
+                    (next(), next(), next())
+                }))
+            })
+            .collect();
+
+        Self { seeds, maps }
+    }
+}
+
+impl Almanac {
+    /// Every map composed into one, taking seeds straight to locations.
+    pub fn seed_to_location(&self) -> PiecewiseMap {
+        self.maps
+            .iter()
+            .fold(PiecewiseMap::new([]), |composed, map| composed.then(map))
+    }
+
+    /// The seeds line read as `start length` pairs, skipping any of no
+    /// length.
+    pub fn seed_ranges(&self) -> Result<IntervalSet<u64>, &'static str> {
+        let pairs = self.seeds.chunks_exact(2);
+        if !pairs.remainder().is_empty() {
+            return Err("a seed range without a length");
+        }
+
+        Ok(pairs
+            .filter_map(|pair| span(pair[0], pair[1]))
+            .map(|(start, end)| start..=end)
+            .collect())
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = Almanac;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Almanac::from(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+"#;
+
+    /// Runs a value through each map in turn, checking each entry directly.
+    fn brute_force(almanac: &Almanac, seed: u64) -> u64 {
+        almanac.maps.iter().fold(seed, |value, map| {
+            map.pieces
+                .iter()
+                .find(|piece| (piece.start..=piece.end).contains(&value))
+                .map_or(value, |piece| piece.get(value))
+        })
+    }
+
+    #[test]
+    fn example() {
+        let almanac = Almanac::from(EXAMPLE);
+        let locations = almanac.seed_to_location();
+
+        assert_eq!(
+            almanac.seeds.iter().map(|&seed| locations.get(seed)).min(),
+            Some(35)
+        );
+        assert_eq!(
+            locations.min_output(&almanac.seed_ranges().unwrap()),
+            Some(46)
+        );
+
+        assert_eq!(part1::solve(&almanac), 35);
+        assert_eq!(part2::solve(&almanac), 46);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let almanac = Almanac::from(EXAMPLE);
+        let locations = almanac.seed_to_location();
+
+        for seed in 0..200 {
+            assert_eq!(
+                locations.get(seed),
+                brute_force(&almanac, seed),
+                "seed {seed}"
+            );
+        }
+
+        let seeds = almanac.seed_ranges().unwrap();
+        let mapped = locations.apply(&seeds);
+        let brute = seeds
+            .iter()
+            .flatten()
+            .map(|seed| brute_force(&almanac, seed))
+            .collect::<std::collections::BTreeSet<_>>();
+        assert_eq!(
+            mapped
+                .iter()
+                .flatten()
+                .collect::<std::collections::BTreeSet<_>>(),
+            brute
+        );
+        assert_eq!(locations.min_output(&seeds), brute.first().copied());
+    }
+
+    #[test]
+    fn seed_ranges() {
+        let almanac = Almanac::from("seeds: 0 0 5 0 79 14 18446744073709551614 2");
+        assert_eq!(
+            almanac.seed_ranges().unwrap().iter().collect::<Vec<_>>(),
+            vec![79..=92, u64::MAX - 1..=u64::MAX]
+        );
+
+        let almanac = Almanac::from("seeds: 79 14 55");
+        assert!(almanac.seed_ranges().is_err());
+
+        let map = PiecewiseMap::new([(0, 10, 0), (5, u64::MAX, 1)]);
+        assert_eq!(map.get(10), 10);
+        assert_eq!(map.get(u64::MAX), 5);
+    }
+
+    #[test]
+    fn keeps_remainders() {
+        // 70..=79 straddles the end of 55..=74, so 75..=79 stays put
+        let map = PiecewiseMap::new([(20, 55, 20)]);
+        let mapped = map.apply(&IntervalSet::from_iter([70..=79]));
+
+        assert_eq!(mapped.iter().collect::<Vec<_>>(), vec![35..=39, 75..=79]);
+    }
+
+    #[test]
+    fn inverse() {
+        let map = PiecewiseMap::new([(50, 98, 2), (52, 50, 48)]);
+        let inverse = map.inverse().unwrap();
+
+        for value in 0..120 {
+            assert_eq!(inverse.get(map.get(value)), value);
+        }
+        assert_eq!(PiecewiseMap::new([(0, 10, 5)]).inverse(), None);
+    }
+}
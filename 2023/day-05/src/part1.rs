@@ -0,0 +1,12 @@
+use crate::Almanac;
+
+pub fn solve(almanac: &Almanac) -> u64 {
+    let locations = almanac.seed_to_location();
+
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| locations.get(seed))
+        .min()
+        .unwrap()
+}
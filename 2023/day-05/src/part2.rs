@@ -0,0 +1,8 @@
+use crate::Almanac;
+
+pub fn solve(almanac: &Almanac) -> u64 {
+    almanac
+        .seed_to_location()
+        .min_output(&almanac.seed_ranges().expect("seeds in start length pairs"))
+        .unwrap()
+}
@@ -0,0 +1,97 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub time_ms: u64,
+    pub distance_mm: u64,
+}
+
+impl Race {
+    fn beats_record(&self, held_time: u64) -> bool {
+        (self.time_ms - held_time) * held_time > self.distance_mm
+    }
+
+    /// How many whole milliseconds the button can be held for to beat the
+    /// record. The distance travelled is a downward parabola in the time
+    /// held, so the winning times are one run either side of its peak.
+    pub fn ways_to_win(&self) -> u64 {
+        let peak = self.time_ms / 2;
+        if !self.beats_record(peak) {
+            return 0;
+        }
+
+        // The largest held time still beating the record, found from the
+        // root of `h * (t - h) = d` and nudged to fix any rounding
+        let (time, distance) = (self.time_ms as f64, self.distance_mm as f64);
+        let mut last = ((time + (time * time - 4.0 * distance).sqrt()) / 2.0) as u64;
+        while last < self.time_ms && self.beats_record(last + 1) {
+            last += 1;
+        }
+        while !self.beats_record(last) {
+            last -= 1;
+        }
+
+        // Holding for `h` and `t - h` go just as far
+        let first = self.time_ms - last;
+        last - first + 1
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = Vec<Race>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        let mut lines = input.lines().map(|line| {
+            line.split(":")
+                .nth(1)
+                .unwrap()
+                .split(" ")
+                .filter_map(|t| t.trim().parse::<u64>().ok())
+        });
+        let times_ms = lines.next().unwrap();
+        let distances_mm = lines.next().unwrap();
+
+        times_ms
+            .zip(distances_mm)
+            .map(|(time_ms, distance_mm)| Race {
+                time_ms,
+                distance_mm,
+            })
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ways_to_win() {
+        for time_ms in 0..40 {
+            for distance_mm in 0..400 {
+                let race = Race {
+                    time_ms,
+                    distance_mm,
+                };
+                let brute = (0..=time_ms)
+                    .filter(|&held| race.beats_record(held))
+                    .count();
+
+                assert_eq!(race.ways_to_win(), brute as u64, "{race:?}");
+            }
+        }
+    }
+}
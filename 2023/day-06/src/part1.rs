@@ -0,0 +1,20 @@
+use crate::Race;
+
+pub fn solve(races: &[Race]) -> u64 {
+    races.iter().map(Race::ways_to_win).product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day06;
+    use utils::Solution;
+
+    #[test]
+    fn example() {
+        let input = r#"Time:      7  15   30
+Distance:  9  40  200"#;
+
+        assert_eq!(solve(&Day06::parse(input)), 288);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::Race;
+
+/// Reads the numbers on each line as one number, ignoring the spaces.
+fn join_kerning(nums: impl Iterator<Item = u64>) -> u64 {
+    nums.map(|num| num.to_string())
+        .collect::<String>()
+        .parse()
+        .unwrap()
+}
+
+pub fn solve(races: &[Race]) -> u64 {
+    Race {
+        time_ms: join_kerning(races.iter().map(|race| race.time_ms)),
+        distance_mm: join_kerning(races.iter().map(|race| race.distance_mm)),
+    }
+    .ways_to_win()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day06;
+    use utils::Solution;
+
+    #[test]
+    fn example() {
+        let input = r#"Time:      7  15   30
+Distance:  9  40  200"#;
+
+        assert_eq!(solve(&Day06::parse(input)), 71503);
+    }
+}
@@ -0,0 +1,274 @@
+//! Classifying and ranking Camel Cards hands under different rule sets.
+
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    /// The type of a hand from the sizes of its two largest groups of
+    /// matching cards.
+    fn from_groups(largest: u8, second: u8) -> Self {
+        match (largest, second) {
+            (5, _) => Self::FiveOfAKind,
+            (4, _) => Self::FourOfAKind,
+            (3, 2) => Self::FullHouse,
+            (3, _) => Self::ThreeOfAKind,
+            (2, 2) => Self::TwoPair,
+            (2, _) => Self::OnePair,
+            _ => Self::HighCard,
+        }
+    }
+}
+
+/// A hand ranked first by its type and then by the strength of each card in
+/// the order they were dealt.
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Hand {
+    pub hand_type: HandType,
+    strengths: [u8; 5],
+}
+
+/// How cards are ranked and whether one of them is wild.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// The strength of each label indexed by its byte, zero when the label
+    /// isn't a card.
+    strengths: [u8; 256],
+    joker: Option<u8>,
+}
+
+impl Rules {
+    pub const STANDARD: Rules = Rules::new(*b"23456789TJQKA", None);
+
+    /// `J` cards are jokers, acting as whatever makes the best hand but the
+    /// weakest card when breaking ties.
+    pub const JOKERS_WILD: Rules = Rules::new(*b"J23456789TQKA", Some(b'J'));
+
+    /// `order` lists the labels from weakest to strongest. A `joker` counts
+    /// as whichever card gives the best hand type, and still ranks by its
+    /// place in `order` when breaking ties.
+    pub const fn new(order: [u8; 13], joker: Option<u8>) -> Self {
+        let mut strengths = [0; 256];
+        let mut idx = 0;
+        while idx < order.len() {
+            assert!(
+                strengths[order[idx] as usize] == 0,
+                "each label should appear once in the order"
+            );
+            strengths[order[idx] as usize] = idx as u8 + 1;
+            idx += 1;
+        }
+
+        Self { strengths, joker }
+    }
+
+    fn strength(&self, card: u8) -> u8 {
+        match self.strengths[card as usize] {
+            0 => panic!("UNEXPECTED VALUE {}", card as char),
+            strength => strength,
+        }
+    }
+
+    /// Counts how many of each card there are, then adds the jokers to the
+    /// largest group since that always gives the best type.
+    pub fn classify(&self, cards: &[u8; 5]) -> HandType {
+        let mut counts = [0u8; 14];
+        let mut jokers = 0;
+        for &card in cards {
+            match self.joker {
+                Some(joker) if joker == card => jokers += 1,
+                _ => counts[self.strength(card) as usize] += 1,
+            }
+        }
+
+        let (mut largest, mut second) = (0, 0);
+        for count in counts {
+            if count > largest {
+                second = largest;
+                largest = count;
+            } else if count > second {
+                second = count;
+            }
+        }
+
+        HandType::from_groups(largest + jokers, second)
+    }
+
+    pub fn hand(&self, cards: &[u8; 5]) -> Hand {
+        Hand {
+            hand_type: self.classify(cards),
+            strengths: cards.map(|card| self.strength(card)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LABELS: &[u8; 13] = b"23456789TJQKA";
+
+    fn hand(rules: &Rules, cards: &str) -> Hand {
+        rules.hand(cards.as_bytes().try_into().unwrap())
+    }
+
+    /// Classifies without jokers by counting the pairs of matching cards,
+    /// which is different for every type.
+    fn pairs_type(cards: &[u8; 5]) -> HandType {
+        let pairs = (0..5)
+            .flat_map(|i| (i + 1..5).map(move |j| (i, j)))
+            .filter(|&(i, j)| cards[i] == cards[j])
+            .count();
+
+        match pairs {
+            10 => HandType::FiveOfAKind,
+            6 => HandType::FourOfAKind,
+            4 => HandType::FullHouse,
+            3 => HandType::ThreeOfAKind,
+            2 => HandType::TwoPair,
+            1 => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+
+    /// Tries every card in place of every joker and keeps the best type.
+    fn brute_force(cards: &[u8; 5]) -> HandType {
+        match cards.iter().position(|&card| card == b'J') {
+            None => pairs_type(cards),
+            Some(idx) => LABELS
+                .iter()
+                .filter(|&&label| label != b'J')
+                .map(|&label| {
+                    let mut cards = *cards;
+                    cards[idx] = label;
+                    brute_force(&cards)
+                })
+                .max()
+                .unwrap(),
+        }
+    }
+
+    #[test]
+    fn ranks() {
+        let lines = r#"AAAAA
+AA8AA
+23332
+TTT98
+23432
+A23A4
+23456"#;
+
+        let hands = lines
+            .lines()
+            .map(|line| hand(&Rules::STANDARD, line).hand_type)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            hands,
+            vec![
+                HandType::FiveOfAKind,
+                HandType::FourOfAKind,
+                HandType::FullHouse,
+                HandType::ThreeOfAKind,
+                HandType::TwoPair,
+                HandType::OnePair,
+                HandType::HighCard,
+            ]
+        );
+    }
+
+    #[test]
+    fn cards() {
+        let standard = LABELS
+            .windows(2)
+            .all(|pair| Rules::STANDARD.strength(pair[0]) < Rules::STANDARD.strength(pair[1]));
+        assert!(standard);
+
+        let jokers = &Rules::JOKERS_WILD;
+        assert!(jokers.strength(b'Q') > jokers.strength(b'T'));
+        assert!(jokers.strength(b'2') > jokers.strength(b'J'));
+    }
+
+    #[test]
+    fn types() {
+        assert!(HandType::FiveOfAKind > HandType::FourOfAKind);
+        assert!(HandType::FourOfAKind > HandType::FullHouse);
+        assert!(HandType::FullHouse > HandType::ThreeOfAKind);
+        assert!(HandType::ThreeOfAKind > HandType::TwoPair);
+        assert!(HandType::TwoPair > HandType::OnePair);
+        assert!(HandType::OnePair > HandType::HighCard);
+    }
+
+    #[test]
+    fn test_eq() {
+        assert!(hand(&Rules::STANDARD, "KK677") > hand(&Rules::STANDARD, "KTJJT"));
+        assert!(hand(&Rules::JOKERS_WILD, "T55J5") < hand(&Rules::JOKERS_WILD, "T5555"));
+        assert_eq!(
+            hand(&Rules::JOKERS_WILD, "T55J5").hand_type,
+            HandType::FourOfAKind
+        );
+    }
+
+    #[test]
+    fn jokers() {
+        let lines = r#"32T3K
+T55J5
+KTJJT
+QJJQ2
+JKKK2
+JJJJA
+JA234
+JJJJJ"#;
+
+        let hands = lines
+            .lines()
+            .map(|line| hand(&Rules::JOKERS_WILD, line).hand_type)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            hands,
+            vec![
+                HandType::OnePair,
+                HandType::FourOfAKind,
+                HandType::FourOfAKind,
+                HandType::FourOfAKind,
+                HandType::FourOfAKind,
+                HandType::FiveOfAKind,
+                HandType::OnePair,
+                HandType::FiveOfAKind,
+            ]
+        );
+    }
+
+    #[test]
+    fn custom_order() {
+        // Aces low with the twos wild
+        let rules = Rules::new(*b"A23456789TJQK", Some(b'2'));
+
+        assert!(hand(&rules, "K3456") > hand(&rules, "A3456"));
+        assert_eq!(hand(&rules, "22KQJ").hand_type, HandType::ThreeOfAKind);
+        assert_eq!(hand(&rules, "AAJJ2").hand_type, HandType::FullHouse);
+    }
+
+    #[test]
+    fn exhaustive() {
+        // Every one of the 13^5 hands, jokers wild and not
+        for mut idx in 0..LABELS.len().pow(5) {
+            let cards: [u8; 5] = std::array::from_fn(|_| {
+                let label = LABELS[idx % LABELS.len()];
+                idx /= LABELS.len();
+                label
+            });
+
+            assert_eq!(Rules::STANDARD.classify(&cards), pairs_type(&cards));
+            assert_eq!(Rules::JOKERS_WILD.classify(&cards), brute_force(&cards));
+        }
+    }
+}
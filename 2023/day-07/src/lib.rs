@@ -0,0 +1,64 @@
+use utils::{Answer, Solution};
+
+use crate::hand::Rules;
+
+pub mod hand;
+pub mod part1;
+pub mod part2;
+
+/// A hand of cards as they appear in the input, along with its bid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub cards: [u8; 5],
+    pub bid: u32,
+}
+
+impl From<&str> for Round {
+    fn from(value: &str) -> Self {
+        let (cards, bid) = value.split_once(' ').unwrap();
+
+        Self {
+            cards: cards.as_bytes().try_into().expect("five cards"),
+            bid: bid.trim().parse().unwrap(),
+        }
+    }
+}
+
+/// Ranks every hand under `rules` and adds up each bid multiplied by its
+/// rank, the weakest hand being rank 1.
+pub fn total_winnings(rounds: &[Round], rules: &Rules) -> u32 {
+    let mut hands = rounds
+        .iter()
+        .map(|round| (rules.hand(&round.cards), round.bid))
+        .collect::<Vec<_>>();
+
+    hands.sort();
+
+    hands
+        .iter()
+        .enumerate()
+        .map(|(idx, (_, bid))| bid * (idx as u32 + 1))
+        .sum::<u32>()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = Vec<Round>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(Round::from)
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
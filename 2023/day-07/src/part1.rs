@@ -0,0 +1,23 @@
+use crate::{hand::Rules, total_winnings, Round};
+
+pub fn solve(rounds: &[Round]) -> u32 {
+    total_winnings(rounds, &Rules::STANDARD)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day07;
+    use utils::Solution;
+
+    #[test]
+    fn example() {
+        let input = r#"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483"#;
+
+        assert_eq!(solve(&Day07::parse(input)), 6440);
+    }
+}
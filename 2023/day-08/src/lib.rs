@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+
+use utils::{cycle, math::crt, Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Network {
+    instructions: Vec<u8>,
+    nodes: HashMap<String, (String, String)>,
+}
+
+impl From<&str> for Network {
+    fn from(value: &str) -> Self {
+        let mut lines = value.lines();
+        let instructions = lines
+            .next()
+            .expect("an instructions line")
+            .as_bytes()
+            .to_vec();
+
+        let nodes = lines
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                (
+                    line[..3].to_string(),
+                    (line[7..10].to_string(), line[12..15].to_string()),
+                )
+            })
+            .collect();
+
+        Self {
+            instructions,
+            nodes,
+        }
+    }
+}
+
+impl Network {
+    pub fn nodes(&self) -> impl Iterator<Item = &str> {
+        self.nodes.keys().map(String::as_str)
+    }
+
+    /// Where following the instruction for step `time` from `node` leads.
+    fn step(&self, node: &str, time: usize) -> &str {
+        let (left, right) = &self.nodes[node];
+        match self.instructions[time % self.instructions.len()] {
+            b'L' => left,
+            b'R' => right,
+            _ => panic!("Invalid direction encountered"),
+        }
+    }
+
+    /// Steps taken from `start` until reaching a node where `is_goal` holds.
+    pub fn steps(&self, start: &str, is_goal: impl Fn(&str) -> bool) -> usize {
+        let mut node = self.step(start, 0);
+        let mut time = 1;
+        while !is_goal(node) {
+            node = self.step(node, time);
+            time += 1;
+        }
+        time
+    }
+
+    /// Follows the instructions from `start` until it is back at the same
+    /// node at the same point in the instructions, after which the walk
+    /// repeats forever.
+    pub fn walk<'a>(&'a self, start: &'a str, is_goal: impl Fn(&str) -> bool) -> Walk {
+        let (cycle, states) = cycle::hashed((start, 0), |&(node, index)| {
+            (
+                self.step(node, index),
+                (index + 1) % self.instructions.len(),
+            )
+        });
+
+        Walk {
+            cycle_start: cycle.mu as u64,
+            cycle_len: cycle.lambda as u64,
+            goals: states
+                .iter()
+                .enumerate()
+                .filter(|(_, (node, _))| is_goal(node))
+                .map(|(time, _)| time as u64)
+                .collect(),
+        }
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed = Network;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Network::from(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
+
+/// The times a walk is at a goal. Goals at or after `cycle_start` repeat
+/// every `cycle_len` steps.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Walk {
+    pub cycle_start: u64,
+    pub cycle_len: u64,
+    pub goals: Vec<u64>,
+}
+
+impl Walk {
+    fn at_goal(&self, time: u64) -> bool {
+        if time < self.cycle_start {
+            return self.goals.contains(&time);
+        }
+
+        let time = self.cycle_start + (time - self.cycle_start) % self.cycle_len;
+        self.goals.contains(&time)
+    }
+
+    fn cycle_goals(&self) -> impl Iterator<Item = u64> + '_ {
+        self.goals
+            .iter()
+            .copied()
+            .filter(|&time| time >= self.cycle_start)
+    }
+}
+
+/// The first time every walk is at a goal at once, `None` if that never
+/// happens.
+pub fn first_together(walks: &[Walk]) -> Option<u64> {
+    // Goals before a walk settles into its cycle only happen once, so just
+    // check whether everything else lines up with them
+    let before_cycles = walks
+        .iter()
+        .flat_map(|walk| walk.goals.iter().filter(|&&time| time < walk.cycle_start))
+        .copied()
+        .filter(|&time| walks.iter().all(|walk| walk.at_goal(time)))
+        .min();
+
+    // Otherwise every walk is in its cycle, so pick a goal from each and
+    // solve for when they coincide
+    let earliest = walks.iter().map(|walk| walk.cycle_start).max().unwrap_or(0) as i128;
+    let mut choices: Vec<Vec<(i128, i128)>> = vec![vec![]];
+    for walk in walks {
+        choices = choices
+            .into_iter()
+            .flat_map(|chosen| {
+                walk.cycle_goals().map(move |time| {
+                    let mut chosen = chosen.clone();
+                    chosen.push((time as i128, walk.cycle_len as i128));
+                    chosen
+                })
+            })
+            .collect();
+    }
+
+    let in_cycles = choices
+        .into_iter()
+        .filter_map(|congruences| {
+            let (time, period) = crt(congruences)?;
+            let behind = (earliest - time).max(0);
+            Some((time + (behind + period - 1) / period * period) as u64)
+        })
+        .min();
+
+    before_cycles.into_iter().chain(in_cycles).min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps() {
+        let input = r#"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"#;
+
+        assert_eq!(Network::from(input).steps("AAA", |node| node == "ZZZ"), 6);
+        assert_eq!(part1::solve(&Day08::parse(input)), 6);
+    }
+
+    #[test]
+    fn ghosts() {
+        let input = r#"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)"#;
+
+        let network = Network::from(input);
+        let walks = network
+            .nodes()
+            .filter(|node| node.ends_with('A'))
+            .map(|start| network.walk(start, |node| node.ends_with('Z')))
+            .collect::<Vec<_>>();
+
+        assert_eq!(first_together(&walks), Some(6));
+        assert_eq!(part2::solve(&network), 6);
+    }
+
+    #[test]
+    fn offset_cycles() {
+        let walks = [
+            Walk {
+                cycle_start: 2,
+                cycle_len: 4,
+                goals: vec![1, 3],
+            },
+            Walk {
+                cycle_start: 0,
+                cycle_len: 6,
+                goals: vec![1],
+            },
+        ];
+        let brute = (0..100).find(|&time| walks.iter().all(|walk| walk.at_goal(time)));
+
+        assert_eq!(brute, Some(1));
+        assert_eq!(first_together(&walks), brute);
+        assert_eq!(first_together(&walks[..1]), Some(1));
+
+        let walks = [
+            walks[0].clone(),
+            Walk {
+                goals: vec![5],
+                ..walks[1].clone()
+            },
+        ];
+        let brute = (0..100).find(|&time| walks.iter().all(|walk| walk.at_goal(time)));
+        assert_eq!(brute, Some(11));
+        assert_eq!(first_together(&walks), brute);
+
+        let never = [
+            Walk {
+                cycle_start: 0,
+                cycle_len: 2,
+                goals: vec![0],
+            },
+            Walk {
+                cycle_start: 0,
+                cycle_len: 4,
+                goals: vec![1],
+            },
+        ];
+        assert_eq!(first_together(&never), None);
+    }
+}
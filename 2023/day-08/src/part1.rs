@@ -0,0 +1,5 @@
+use crate::Network;
+
+pub fn solve(network: &Network) -> usize {
+    network.steps("AAA", |node| node == "ZZZ")
+}
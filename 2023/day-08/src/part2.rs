@@ -0,0 +1,14 @@
+use crate::{first_together, Network};
+
+// Walking every ghost in lockstep takes far too long. Instead find where
+// each ghost's walk starts repeating and when it passes a `..Z` node, then
+// work out when those line up.
+pub fn solve(network: &Network) -> u64 {
+    let walks = network
+        .nodes()
+        .filter(|node| node.ends_with('A'))
+        .map(|start| network.walk(start, |node| node.ends_with('Z')))
+        .collect::<Vec<_>>();
+
+    first_together(&walks).expect("the ghosts to meet")
+}
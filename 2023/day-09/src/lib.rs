@@ -0,0 +1,31 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    /// The history of values on each line, which can be negative
+    type Parsed = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|val| val.parse::<i32>().unwrap())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
@@ -0,0 +1,47 @@
+fn next_value(history: &[i32]) -> i32 {
+    let mut vals = history.to_vec();
+
+    (0..vals.len())
+        // Iteratre backwards through our iterator
+        .rev()
+        .map(|idx| {
+            // Walk the windows of the current size
+            // Each iteration, we will see 1 less entry than we did before
+            for i in 0..idx {
+                // Update our left most number to be the difference between
+                // the two entries
+                vals[i] = vals[i + 1] - vals[i];
+            }
+
+            vals[idx]
+        })
+        // Since we are replacing each entry in the list, we can sum up
+        // every entry (0s are effectively ignored) to get our total.
+        // We see one less entry on every iteration so the previous iterations
+        // value will be right most
+        .sum::<i32>()
+}
+
+pub fn solve(histories: &[Vec<i32>]) -> i32 {
+    histories.iter().map(|history| next_value(history)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day09;
+    use utils::Solution;
+
+    #[test]
+    fn example() {
+        let input = r#"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"#;
+        let histories = Day09::parse(input);
+
+        assert_eq!(next_value(&histories[0]), 18);
+        assert_eq!(next_value(&histories[1]), 28);
+        assert_eq!(next_value(&histories[2]), 68);
+        assert_eq!(solve(&histories), 114);
+    }
+}
@@ -0,0 +1,58 @@
+use itertools::{Itertools, Position};
+
+fn previous_value(history: &[i32]) -> i32 {
+    let mut vals = history.to_vec();
+    let mut start_numbers: Vec<i32> = vec![];
+
+    loop {
+        if vals.iter().all(|n| n == &0) {
+            break;
+        }
+
+        vals = vals
+            .iter()
+            .tuple_windows()
+            .with_position()
+            .map(|(pos, (l, r))| {
+                // If it is the first or the only element in the iterator
+                // we will add it to our initial numbers
+                match pos {
+                    Position::First | Position::Only => {
+                        start_numbers.push(*l);
+                    }
+                    _ => {}
+                };
+
+                r - l
+            })
+            .collect::<Vec<i32>>();
+    }
+
+    // Reduce over all of our numbers
+    start_numbers.iter().rev().fold(0, |acc, num| num - acc)
+}
+
+pub fn solve(histories: &[Vec<i32>]) -> i32 {
+    histories
+        .iter()
+        .map(|history| previous_value(history))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day09;
+    use utils::Solution;
+
+    #[test]
+    fn example() {
+        let input = r#"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"#;
+        let histories = Day09::parse(input);
+
+        assert_eq!(previous_value(&histories[2]), 5);
+        assert_eq!(solve(&histories), 2);
+    }
+}
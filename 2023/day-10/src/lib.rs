@@ -0,0 +1,380 @@
+use utils::{geometry::Polygon, Answer, Direction, Grid, Point2, Position, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Pipe {
+    Vertical,
+    Horizontal,
+    NorthEast90,
+    NorthWest90,
+    SouthWest90,
+    SouthEast90,
+    Ground,
+    Start,
+}
+
+/// `{}` writes the pipe as it appears in the input, `{:#}` as a box-drawing
+/// character.
+impl std::fmt::Display for Pipe {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let val = match (self, f.alternate()) {
+            (Self::Vertical, false) => "|",
+            (Self::Horizontal, false) => "-",
+            (Self::NorthEast90, false) => "L",
+            (Self::NorthWest90, false) => "J",
+            (Self::SouthWest90, false) => "7",
+            (Self::SouthEast90, false) => "F",
+            (Self::Ground, false) => ".",
+            (Self::Vertical, true) => "│",
+            (Self::Horizontal, true) => "─",
+            (Self::NorthEast90, true) => "└",
+            (Self::NorthWest90, true) => "┘",
+            (Self::SouthWest90, true) => "┐",
+            (Self::SouthEast90, true) => "┌",
+            (Self::Ground, true) => " ",
+            (Self::Start, _) => "S",
+        };
+        write!(f, "{}", val)
+    }
+}
+
+impl From<char> for Pipe {
+    fn from(value: char) -> Self {
+        match value {
+            '|' => Self::Vertical,
+            '-' => Self::Horizontal,
+            'L' => Self::NorthEast90,
+            'J' => Self::NorthWest90,
+            '7' => Self::SouthWest90,
+            'F' => Self::SouthEast90,
+            '.' => Self::Ground,
+            'S' => Self::Start,
+            _ => panic!("Invalid pipe character encountered: {value} "),
+        }
+    }
+}
+
+impl Pipe {
+    const CONNECTING: [Pipe; 6] = [
+        Self::Vertical,
+        Self::Horizontal,
+        Self::NorthEast90,
+        Self::NorthWest90,
+        Self::SouthWest90,
+        Self::SouthEast90,
+    ];
+
+    /// The two directions this pipe leads in, `None` for tiles that aren't
+    /// a known pipe.
+    pub fn dirs(&self) -> Option<[Direction; 2]> {
+        match self {
+            Self::Vertical => Some([Direction::Up, Direction::Down]),
+            Self::Horizontal => Some([Direction::Left, Direction::Right]),
+            Self::NorthWest90 => Some([Direction::Up, Direction::Left]),
+            Self::NorthEast90 => Some([Direction::Up, Direction::Right]),
+            Self::SouthEast90 => Some([Direction::Down, Direction::Right]),
+            Self::SouthWest90 => Some([Direction::Down, Direction::Left]),
+            _ => None,
+        }
+    }
+
+    fn connects(&self, dir: Direction) -> bool {
+        self.dirs().is_some_and(|dirs| dirs.contains(&dir))
+    }
+}
+
+pub struct Maze {
+    pub tiles: Grid<Pipe>,
+    pub start: Position,
+}
+
+impl From<&str> for Maze {
+    fn from(value: &str) -> Self {
+        let tiles = Grid::<Pipe>::from(value.trim());
+        let (start, _) = tiles
+            .iter_with_coords()
+            .find(|(_, &pipe)| pipe == Pipe::Start)
+            .expect("a start tile");
+
+        Self { tiles, start }
+    }
+}
+
+impl Maze {
+    /// Whether the tile next to `S` in `dir` leads back into it.
+    fn leads_back(&self, dir: Direction) -> bool {
+        self.start
+            .new_in_dir(dir)
+            .and_then(|next| self.tiles.get(&next))
+            .is_some_and(|pipe| pipe.connects(dir.opposite()))
+    }
+
+    /// The pipe hidden under `S`. Stray pipes can lead into `S` as well as
+    /// the two on the loop, so this is the pipe whose walk comes back round.
+    pub fn start_pipe(&self) -> Option<Pipe> {
+        self.main_loop()
+            .and_then(|main_loop| main_loop.pipes.get(&self.start).copied())
+    }
+
+    /// Follows the pipes from `S` back around to it, trying each pipe that
+    /// could be under `S` until one of them closes the loop.
+    pub fn main_loop(&self) -> Option<Loop> {
+        Pipe::CONNECTING
+            .into_iter()
+            .filter(|pipe| pipe.dirs().unwrap().iter().all(|&dir| self.leads_back(dir)))
+            .find_map(|pipe| self.walk(pipe))
+    }
+
+    /// Walks the loop with `start_pipe` under `S`, `None` when the pipes
+    /// run out or come back into `S` from a side it doesn't connect to.
+    fn walk(&self, start_pipe: Pipe) -> Option<Loop> {
+        let mut pipes = self.tiles.clone();
+        pipes.set(&self.start, start_pipe);
+
+        let mut tiles = vec![];
+        let mut position = self.start;
+        let mut dir = start_pipe.dirs()?[0];
+        loop {
+            tiles.push(position);
+            position = position.new_in_dir(dir)?;
+            if position == self.start {
+                if !start_pipe.connects(dir.opposite()) {
+                    return None;
+                }
+                break;
+            }
+
+            let pipe = pipes.get(&position)?;
+            let [first, second] = pipe.dirs()?;
+            dir = match dir.opposite() {
+                from if from == first => second,
+                from if from == second => first,
+                _ => return None,
+            };
+        }
+
+        let mut on_loop = Grid::new(pipes.width(), pipes.height(), false);
+        for tile in &tiles {
+            on_loop.set(tile, true);
+        }
+
+        Some(Loop {
+            tiles,
+            pipes,
+            on_loop,
+        })
+    }
+}
+
+/// The loop through `S`, with `S` swapped for the pipe under it.
+pub struct Loop {
+    /// Every tile on the loop in the order they are visited, starting at `S`.
+    pub tiles: Vec<Position>,
+    pipes: Grid<Pipe>,
+    on_loop: Grid<bool>,
+}
+
+impl Loop {
+    /// Steps along the loop to the tile furthest from `S`.
+    pub fn farthest(&self) -> usize {
+        self.tiles.len() / 2
+    }
+
+    fn is_enclosed(&self, position: &Position, inside: bool) -> bool {
+        inside && self.on_loop.get(position) == Some(&false)
+    }
+
+    /// Scans each row keeping track of whether it is inside the loop. Only
+    /// the loop tiles leading up cross it, so a `└─┐` run counts as one
+    /// crossing while `└─┘` doesn't count at all.
+    pub fn enclosed_by_scanline(&self) -> usize {
+        let mut enclosed = 0;
+        for y in 0..self.pipes.height() {
+            let mut inside = false;
+            for x in 0..self.pipes.width() {
+                let position = Position::new(x, y);
+                if self.on_loop.get(&position) == Some(&true) {
+                    inside ^= self.pipes.get(&position).unwrap().connects(Direction::Up);
+                } else if self.is_enclosed(&position, inside) {
+                    enclosed += 1;
+                }
+            }
+        }
+        enclosed
+    }
+
+    /// Treats the tile centres as the vertices of a polygon, whose interior
+    /// lattice points are the enclosed tiles by Pick's theorem.
+    pub fn enclosed_by_area(&self) -> usize {
+        let vertices = self
+            .tiles
+            .iter()
+            .map(|&Position(x, y)| Point2::new(x as i128, y as i128))
+            .collect();
+
+        Polygon::new(vertices).interior() as usize
+    }
+
+    /// Draws the loop with box-drawing characters, with the tiles it
+    /// encloses as `I` and every other tile as `.`.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for y in 0..self.pipes.height() {
+            let mut inside = false;
+            for x in 0..self.pipes.width() {
+                let position = Position::new(x, y);
+                let pipe = self.pipes.get(&position).unwrap();
+                if self.on_loop.get(&position) == Some(&true) {
+                    inside ^= pipe.connects(Direction::Up);
+                    out.push_str(&format!("{pipe:#}"));
+                } else if self.is_enclosed(&position, inside) {
+                    out.push('I');
+                } else {
+                    out.push('.');
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Maze;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Maze::from(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn farthest() {
+        let input = r#".....
+.S-7.
+.|.|.
+.L-J.
+....."#;
+        let maze = Maze::from(input);
+        assert_eq!(maze.start_pipe(), Some(Pipe::SouthEast90));
+        assert_eq!(maze.main_loop().unwrap().farthest(), 4);
+
+        let input = r#"..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ..."#;
+        let maze = Maze::from(input);
+        assert_eq!(maze.start_pipe(), Some(Pipe::SouthEast90));
+        assert_eq!(maze.main_loop().unwrap().farthest(), 8);
+        assert_eq!(part1::solve(&maze), 8);
+    }
+
+    #[test]
+    fn stray_pipes() {
+        // Both `-` either side of `S` and the `|` below it lead into it
+        let input = r#".....
+-S-7.
+.|.|.
+.L-J.
+....."#;
+        let maze = Maze::from(input);
+        assert_eq!(maze.start_pipe(), Some(Pipe::SouthEast90));
+        assert_eq!(part1::solve(&maze), 4);
+        assert_eq!(part2::solve(&maze), 1);
+
+        let input = r#"..|..
+.FS7.
+.L-J."#;
+        let maze = Maze::from(input);
+        assert_eq!(maze.start_pipe(), Some(Pipe::Horizontal));
+        assert_eq!(maze.main_loop().unwrap().tiles.len(), 6);
+    }
+
+    #[test]
+    fn enclosed() {
+        let examples = [
+            (
+                r#"...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+..........."#,
+                4,
+            ),
+            (
+                r#".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ..."#,
+                8,
+            ),
+            (
+                r#"FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L"#,
+                10,
+            ),
+        ];
+
+        for (input, expected) in examples {
+            let maze = Maze::from(input);
+            let main_loop = maze.main_loop().unwrap();
+            assert_eq!(main_loop.enclosed_by_scanline(), expected);
+            assert_eq!(main_loop.enclosed_by_area(), expected);
+            assert_eq!(part2::solve(&maze), expected);
+        }
+    }
+
+    #[test]
+    fn render() {
+        let input = r#"-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF"#;
+
+        assert_eq!(
+            Maze::from(input).main_loop().unwrap().render(),
+            r#".....
+.┌─┐.
+.│I│.
+.└─┘.
+.....
+"#
+        );
+        assert_eq!(format!("{}", Pipe::SouthEast90), "F");
+    }
+}
@@ -0,0 +1,8 @@
+use crate::Maze;
+
+// Follow the loop from `S` back to itself, the furthest tile is halfway round
+pub fn solve(maze: &Maze) -> usize {
+    maze.main_loop()
+        .expect("a loop through the start")
+        .farthest()
+}
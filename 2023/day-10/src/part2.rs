@@ -0,0 +1,9 @@
+use crate::Maze;
+
+// Crossing the loop flips between outside and inside, so scan each row and
+// count the tiles passed while inside
+pub fn solve(maze: &Maze) -> usize {
+    maze.main_loop()
+        .expect("a loop through the start")
+        .enclosed_by_scanline()
+}
@@ -0,0 +1,63 @@
+use utils::{geometry::Polygon, Answer, Direction, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug, Clone)]
+pub struct DigStep {
+    pub dir: Direction,
+    pub dist: i128,
+    pub color: String,
+}
+
+impl From<&str> for DigStep {
+    fn from(value: &str) -> Self {
+        let mut parts = value.split(" ");
+
+        Self {
+            dir: match parts.next().unwrap() {
+                "R" => Direction::Right,
+                "U" => Direction::Up,
+                "D" => Direction::Down,
+                "L" => Direction::Left,
+                dir => panic!("Unexpected direction string {}", dir),
+            },
+            dist: parts.next().unwrap().parse::<i128>().unwrap(),
+            color: parts
+                .next()
+                .unwrap()
+                .trim_start_matches("(#")
+                .trim_end_matches(")")
+                .to_string(),
+        }
+    }
+}
+
+/// Cells dug out by following `steps`, trench included.
+pub fn lagoon_size(steps: impl IntoIterator<Item = (Direction, i128)>) -> i128 {
+    // Every dug cell is a lattice point of the polygon through the cell
+    // centres, whether it is on the trench or inside of it
+    Polygon::from_steps(steps).lattice_points()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed = Vec<DigStep>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(DigStep::from)
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
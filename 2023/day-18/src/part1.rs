@@ -0,0 +1,32 @@
+use crate::{lagoon_size, DigStep};
+
+pub fn solve(steps: &[DigStep]) -> i128 {
+    lagoon_size(steps.iter().map(|step| (step.dir, step.dist)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day18;
+    use utils::Solution;
+
+    #[test]
+    fn example() {
+        let input = r#"R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)"#;
+
+        assert_eq!(solve(&Day18::parse(input)), 62);
+    }
+}
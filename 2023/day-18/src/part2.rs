@@ -0,0 +1,52 @@
+use utils::Direction;
+
+use crate::{lagoon_size, DigStep};
+
+// The real instructions are hidden in the colour, `70c710` is the distance
+// 0x70c71 followed by a direction digit
+fn decode(color: &str) -> (Direction, i128) {
+    let (dist, dir) = color.split_at(5);
+
+    (
+        match dir {
+            "0" => Direction::Right,
+            "1" => Direction::Down,
+            "2" => Direction::Left,
+            "3" => Direction::Up,
+            _ => panic!("Unexpected direction digit {}", dir),
+        },
+        i128::from_str_radix(dist, 16).unwrap(),
+    )
+}
+
+pub fn solve(steps: &[DigStep]) -> i128 {
+    lagoon_size(steps.iter().map(|step| decode(&step.color)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day18;
+    use utils::Solution;
+
+    #[test]
+    fn example() {
+        let input = r#"R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)"#;
+
+        assert_eq!(decode("70c710"), (Direction::Right, 461937));
+        assert_eq!(solve(&Day18::parse(input)), 952408144115);
+    }
+}
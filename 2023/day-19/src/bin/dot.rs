@@ -0,0 +1,11 @@
+use aoc_2023_day_19::System;
+
+// Prints the workflows flattened into a single tree, for piping into `dot`
+fn main() {
+    print!(
+        "{}",
+        System::from(utils::input_from_args(2023, 19).as_str())
+            .compile()
+            .to_dot()
+    );
+}
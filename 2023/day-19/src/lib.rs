@@ -0,0 +1,576 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// The smallest and largest value any category of a rating can have.
+pub const RATING_RANGE: (usize, usize) = (1, 4000);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Destination {
+    Accept,
+    Reject,
+    Rule(String),
+}
+
+impl From<String> for Destination {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "A" => Self::Accept,
+            "R" => Self::Reject,
+            _ => Self::Rule(value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    LT,
+    GT,
+}
+
+impl From<char> for Operand {
+    fn from(value: char) -> Self {
+        match value {
+            '<' => Self::LT,
+            '>' => Self::GT,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl From<char> for Part {
+    fn from(value: char) -> Self {
+        match value {
+            'x' => Self::X,
+            'a' => Self::A,
+            'm' => Self::M,
+            's' => Self::S,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl From<&str> for Part {
+    fn from(value: &str) -> Self {
+        Self::from(value.chars().next().unwrap())
+    }
+}
+
+impl Part {
+    fn index(&self) -> usize {
+        match self {
+            Part::X => 0,
+            Part::M => 1,
+            Part::A => 2,
+            Part::S => 3,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let part = match self {
+            Part::X => 'x',
+            Part::M => 'm',
+            Part::A => 'a',
+            Part::S => 's',
+        };
+        write!(f, "{part}")
+    }
+}
+
+#[derive(Debug)]
+pub struct Rating {
+    pub x: usize,
+    pub m: usize,
+    pub a: usize,
+    pub s: usize,
+}
+
+impl Rating {
+    pub fn sum(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
+
+    fn get(&self, part: Part) -> usize {
+        match part {
+            Part::X => self.x,
+            Part::M => self.m,
+            Part::A => self.a,
+            Part::S => self.s,
+        }
+    }
+}
+
+/// Every rating with each category inside an inclusive range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hypercube {
+    ranges: [(usize, usize); 4],
+}
+
+impl Default for Hypercube {
+    /// Every rating there could be.
+    fn default() -> Self {
+        Self {
+            ranges: [RATING_RANGE; 4],
+        }
+    }
+}
+
+impl Hypercube {
+    pub fn len(&self) -> usize {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| (end + 1).saturating_sub(start))
+            .product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn with(mut self, part: Part, range: (usize, usize)) -> Option<Self> {
+        self.ranges[part.index()] = range;
+        (!self.is_empty()).then_some(self)
+    }
+
+    /// The ratings that do and don't meet `condition`.
+    pub fn split(&self, condition: &Condition) -> (Option<Self>, Option<Self>) {
+        let (start, end) = self.ranges[condition.part.index()];
+        let (pass, fail) = match condition.operand {
+            Operand::LT => (
+                (start, end.min(condition.value.saturating_sub(1))),
+                (start.max(condition.value), end),
+            ),
+            Operand::GT => (
+                (start.max(condition.value + 1), end),
+                (start, end.min(condition.value)),
+            ),
+        };
+
+        (
+            self.with(condition.part, pass),
+            self.with(condition.part, fail),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condition {
+    part: Part,
+    value: usize,
+    operand: Operand,
+}
+
+impl From<String> for Condition {
+    fn from(value: String) -> Self {
+        let mut value = value.chars();
+
+        Self {
+            part: value.next().unwrap().into(),
+            operand: value.next().unwrap().into(),
+            value: value.collect::<String>().parse::<usize>().unwrap(),
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operand = match self.operand {
+            Operand::LT => '<',
+            Operand::GT => '>',
+        };
+        write!(f, "{}{}{}", self.part, operand, self.value)
+    }
+}
+
+impl Condition {
+    fn is_valid(&self, rating: &Rating) -> bool {
+        match self.operand {
+            Operand::LT => rating.get(self.part) < self.value,
+            Operand::GT => rating.get(self.part) > self.value,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Rule {
+    condition: Option<Condition>,
+    destination: Destination,
+}
+
+impl From<String> for Rule {
+    fn from(value: String) -> Self {
+        if !value.contains(":") {
+            return Self {
+                condition: None,
+                destination: value.into(),
+            };
+        };
+
+        let (cond, dest) = value.split_once(":").unwrap();
+
+        Self {
+            condition: Some(cond.to_string().into()),
+            destination: dest.to_string().into(),
+        }
+    }
+}
+
+impl Rule {
+    fn next_dest(&self, rating: &Rating) -> Option<&Destination> {
+        match &self.condition {
+            Some(cond) if !cond.is_valid(rating) => None,
+            _ => Some(&self.destination),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Workflow {
+    rules: Vec<Rule>,
+}
+
+/// The workflows, named by the rules that send parts to them, along with
+/// the ratings of the parts to sort.
+#[derive(Debug)]
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+    pub ratings: Vec<Rating>,
+}
+
+impl From<&str> for System {
+    fn from(value: &str) -> Self {
+        let mut input = value.split("\n\n");
+        let workflows = input
+            .next()
+            .unwrap()
+            .lines()
+            .map(|flow| {
+                let (name, rules) = flow.split_once("{").unwrap();
+
+                (
+                    name.to_string(),
+                    Workflow {
+                        rules: rules
+                            .split(",")
+                            .map(|rule| rule.replace("}", "").into())
+                            .collect::<Vec<Rule>>(),
+                    },
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        let ratings = input
+            .next()
+            .unwrap_or_default()
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(|line| {
+                let ratings = &line[1..line.len() - 1]
+                    .split(",")
+                    .map(|rating| {
+                        let (_, value) = rating.split_once("=").unwrap();
+
+                        value.parse::<usize>().unwrap()
+                    })
+                    .collect::<Vec<_>>();
+
+                Rating {
+                    x: ratings[0],
+                    m: ratings[1],
+                    a: ratings[2],
+                    s: ratings[3],
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Self { workflows, ratings }
+    }
+}
+
+impl System {
+    fn workflow(&self, name: &str) -> &Workflow {
+        self.workflows
+            .get(name)
+            .unwrap_or_else(|| panic!("No workflow named {name}"))
+    }
+
+    pub fn is_accepted(&self, rating: &Rating) -> bool {
+        let mut dest = &Destination::Rule("in".to_string());
+        loop {
+            match dest {
+                Destination::Accept => return true,
+                Destination::Reject => return false,
+                Destination::Rule(name) => {
+                    // The last rule of every workflow has no condition
+                    dest = self
+                        .workflow(name)
+                        .rules
+                        .iter()
+                        .find_map(|rule| rule.next_dest(rating))
+                        .expect("a rule to match");
+                }
+            }
+        }
+    }
+
+    /// How many ratings in `cube` end up accepted, by sending the whole
+    /// range through the workflows and splitting it at each condition.
+    pub fn count_accepted(&self, cube: Hypercube) -> usize {
+        self.count_from(&Destination::Rule("in".to_string()), cube)
+    }
+
+    fn count_from(&self, dest: &Destination, cube: Hypercube) -> usize {
+        let name = match dest {
+            Destination::Accept => return cube.len(),
+            Destination::Reject => return 0,
+            Destination::Rule(name) => name,
+        };
+
+        let mut count = 0;
+        let mut rest = Some(cube);
+        for rule in &self.workflow(name).rules {
+            let Some(cube) = rest else {
+                break;
+            };
+            let (pass, fail) = match &rule.condition {
+                Some(cond) => cube.split(cond),
+                None => (Some(cube), None),
+            };
+            if let Some(pass) = pass {
+                count += self.count_from(&rule.destination, pass);
+            }
+            rest = fail;
+        }
+
+        count
+    }
+
+    /// Inlines every workflow from `in` into a single tree of conditions.
+    pub fn compile(&self) -> Decision {
+        self.compile_rules(&self.workflow("in").rules)
+    }
+
+    fn compile_rules(&self, rules: &[Rule]) -> Decision {
+        let Some((rule, rest)) = rules.split_first() else {
+            panic!("Workflow has no unconditional rule");
+        };
+
+        let pass = match &rule.destination {
+            Destination::Accept => Decision::Accept,
+            Destination::Reject => Decision::Reject,
+            Destination::Rule(name) => self.compile_rules(&self.workflow(name).rules),
+        };
+        let Some(condition) = &rule.condition else {
+            return pass;
+        };
+        let fail = self.compile_rules(rest);
+
+        // Both sides agreeing makes the condition pointless
+        if pass == fail {
+            return pass;
+        }
+
+        Decision::Branch {
+            condition: condition.clone(),
+            pass: Box::new(pass),
+            fail: Box::new(fail),
+        }
+    }
+}
+
+/// The workflows flattened into one binary tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Decision {
+    Accept,
+    Reject,
+    Branch {
+        condition: Condition,
+        pass: Box<Decision>,
+        fail: Box<Decision>,
+    },
+}
+
+impl Decision {
+    pub fn is_accepted(&self, rating: &Rating) -> bool {
+        match self {
+            Decision::Accept => true,
+            Decision::Reject => false,
+            Decision::Branch {
+                condition,
+                pass,
+                fail,
+            } => match condition.is_valid(rating) {
+                true => pass.is_accepted(rating),
+                false => fail.is_accepted(rating),
+            },
+        }
+    }
+
+    pub fn count_accepted(&self, cube: Hypercube) -> usize {
+        match self {
+            Decision::Accept => cube.len(),
+            Decision::Reject => 0,
+            Decision::Branch {
+                condition,
+                pass,
+                fail,
+            } => {
+                let (pass_cube, fail_cube) = cube.split(condition);
+                pass_cube.map_or(0, |cube| pass.count_accepted(cube))
+                    + fail_cube.map_or(0, |cube| fail.count_accepted(cube))
+            }
+        }
+    }
+
+    /// The tree as a Graphviz `digraph`, with conditions as ellipses and
+    /// accept or reject as boxes.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph workflows {\n");
+        self.write_dot(&mut dot, &mut 0);
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Writes this node and everything under it, returning its id.
+    fn write_dot(&self, dot: &mut String, next_id: &mut usize) -> usize {
+        let id = *next_id;
+        *next_id += 1;
+
+        match self {
+            Decision::Accept => dot.push_str(&format!("    n{id} [label=\"A\", shape=box];\n")),
+            Decision::Reject => dot.push_str(&format!("    n{id} [label=\"R\", shape=box];\n")),
+            Decision::Branch {
+                condition,
+                pass,
+                fail,
+            } => {
+                dot.push_str(&format!("    n{id} [label=\"{condition}\"];\n"));
+                let pass = pass.write_dot(dot, next_id);
+                dot.push_str(&format!("    n{id} -> n{pass} [label=\"yes\"];\n"));
+                let fail = fail.write_dot(dot, next_id);
+                dot.push_str(&format!("    n{id} -> n{fail} [label=\"no\"];\n"));
+            }
+        }
+
+        id
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed = System;
+
+    fn parse(input: &str) -> Self::Parsed {
+        System::from(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}"#;
+
+    #[test]
+    fn ratings() {
+        let system = System::from(EXAMPLE);
+        let decision = system.compile();
+
+        let accepted = system
+            .ratings
+            .iter()
+            .filter(|rating| system.is_accepted(rating))
+            .map(Rating::sum)
+            .sum::<usize>();
+        assert_eq!(accepted, 19114);
+        assert_eq!(part1::solve(&system), 19114);
+
+        for rating in &system.ratings {
+            assert_eq!(decision.is_accepted(rating), system.is_accepted(rating));
+        }
+    }
+
+    #[test]
+    fn combinations() {
+        let system = System::from(EXAMPLE);
+
+        assert_eq!(system.count_accepted(Hypercube::default()), 167409079868000);
+        assert_eq!(part2::solve(&system), 167409079868000);
+        assert_eq!(
+            system.compile().count_accepted(Hypercube::default()),
+            167409079868000
+        );
+    }
+
+    #[test]
+    fn split() {
+        let cube = Hypercube::default();
+        let (pass, fail) = cube.split(&Condition::from("x<1".to_string()));
+        assert_eq!(pass, None);
+        assert_eq!(fail, Some(cube));
+
+        let (pass, fail) = cube.split(&Condition::from("m>3000".to_string()));
+        assert_eq!(pass.unwrap().len(), 1000 * 4000 * 4000 * 4000);
+        assert_eq!(fail.unwrap().len(), 3000 * 4000 * 4000 * 4000);
+    }
+
+    #[test]
+    fn dot() {
+        let system = System::from("in{s<1351:px,lnx}\npx{a>10:R,R}\nlnx{m>1548:A,R}");
+
+        assert_eq!(
+            system.compile().to_dot(),
+            r#"digraph workflows {
+    n0 [label="s<1351"];
+    n1 [label="R", shape=box];
+    n0 -> n1 [label="yes"];
+    n2 [label="m>1548"];
+    n3 [label="A", shape=box];
+    n2 -> n3 [label="yes"];
+    n4 [label="R", shape=box];
+    n2 -> n4 [label="no"];
+    n0 -> n2 [label="no"];
+}
+"#
+        );
+    }
+}
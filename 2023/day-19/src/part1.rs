@@ -0,0 +1,12 @@
+use crate::{Rating, System};
+
+// Each part starts at the `in` workflow and follows the first rule whose
+// condition it meets until it is accepted or rejected
+pub fn solve(system: &System) -> usize {
+    system
+        .ratings
+        .iter()
+        .filter(|rating| system.is_accepted(rating))
+        .map(Rating::sum)
+        .sum()
+}
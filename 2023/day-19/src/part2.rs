@@ -0,0 +1,7 @@
+use crate::{Hypercube, System};
+
+// Rather than trying all 4000^4 ratings, send every rating through at once
+// as ranges and split them whenever a condition cuts through
+pub fn solve(system: &System) -> usize {
+    system.count_accepted(Hypercube::default())
+}
@@ -0,0 +1,383 @@
+use std::collections::{HashMap, VecDeque};
+
+use utils::{math::crt, Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// Gives up looking for the cycles feeding a module after this many presses.
+const MAX_PRESSES: u64 = 1 << 16;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ModuleId(usize);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Pulse {
+    Low,
+    High,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Signal {
+    pub from: ModuleId,
+    pub to: ModuleId,
+    pub pulse: Pulse,
+}
+
+/// Pulses sent during one or more button presses.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Counts {
+    pub low: u64,
+    pub high: u64,
+}
+
+#[derive(Clone, Debug)]
+enum Kind {
+    Broadcaster,
+    FlipFlop {
+        on: bool,
+    },
+    Conjunction {
+        memory: Vec<(ModuleId, Pulse)>,
+    },
+    /// Anything only ever sent pulses, like `output`.
+    Untyped,
+}
+
+#[derive(Clone, Debug)]
+struct Module {
+    kind: Kind,
+    destinations: Vec<ModuleId>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Circuit {
+    names: Vec<String>,
+    ids: HashMap<String, ModuleId>,
+    modules: Vec<Module>,
+    button: ModuleId,
+}
+
+impl From<&str> for Circuit {
+    fn from(value: &str) -> Self {
+        let mut circuit = Self {
+            names: vec![],
+            ids: HashMap::new(),
+            modules: vec![],
+            button: ModuleId(0),
+        };
+        circuit.button = circuit.add("button");
+
+        for line in value.lines().filter(|line| !line.is_empty()) {
+            let (source, destinations) = line.split_once(" -> ").expect("a wiring line");
+            let (kind, name) = match source.as_bytes()[0] {
+                b'%' => (Kind::FlipFlop { on: false }, &source[1..]),
+                b'&' => (Kind::Conjunction { memory: vec![] }, &source[1..]),
+                _ if source == "broadcaster" => (Kind::Broadcaster, source),
+                _ => panic!("Invalid module encountered"),
+            };
+
+            let id = circuit.add(name);
+            let destinations = destinations
+                .split(", ")
+                .map(|destination| circuit.add(destination))
+                .collect();
+            circuit.modules[id.0] = Module { kind, destinations };
+        }
+
+        // Conjunctions need to remember a pulse for each of their inputs
+        for from in 0..circuit.modules.len() {
+            for to in circuit.modules[from].destinations.clone() {
+                if let Kind::Conjunction { memory } = &mut circuit.modules[to.0].kind {
+                    memory.push((ModuleId(from), Pulse::Low));
+                }
+            }
+        }
+
+        circuit
+    }
+}
+
+impl Circuit {
+    fn add(&mut self, name: &str) -> ModuleId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+
+        let id = ModuleId(self.modules.len());
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.modules.push(Module {
+            kind: Kind::Untyped,
+            destinations: vec![],
+        });
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<ModuleId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: ModuleId) -> &str {
+        &self.names[id.0]
+    }
+
+    /// Modules sending pulses to `id`.
+    pub fn inputs(&self, id: ModuleId) -> Vec<ModuleId> {
+        (0..self.modules.len())
+            .map(ModuleId)
+            .filter(|input| self.modules[input.0].destinations.contains(&id))
+            .collect()
+    }
+
+    /// Turns every flip-flop off and has every conjunction forget its inputs,
+    /// as before the first press.
+    pub fn reset(&mut self) {
+        for module in &mut self.modules {
+            match &mut module.kind {
+                Kind::FlipFlop { on } => *on = false,
+                Kind::Conjunction { memory } => {
+                    memory.iter_mut().for_each(|(_, pulse)| *pulse = Pulse::Low)
+                }
+                Kind::Broadcaster | Kind::Untyped => {}
+            }
+        }
+    }
+
+    /// Presses the button and sends pulses in the order they were sent until
+    /// the circuit settles, showing each one to `watch` as it arrives.
+    pub fn press(&mut self, mut watch: impl FnMut(&Signal)) -> Counts {
+        let mut counts = Counts::default();
+        let mut queue = VecDeque::from([Signal {
+            from: self.button,
+            to: self.id("broadcaster").expect("a broadcaster"),
+            pulse: Pulse::Low,
+        }]);
+
+        while let Some(signal) = queue.pop_front() {
+            watch(&signal);
+            match signal.pulse {
+                Pulse::Low => counts.low += 1,
+                Pulse::High => counts.high += 1,
+            }
+
+            let module = &mut self.modules[signal.to.0];
+            let pulse = match &mut module.kind {
+                Kind::Broadcaster => Some(signal.pulse),
+                Kind::FlipFlop { on } => match signal.pulse {
+                    Pulse::High => None,
+                    Pulse::Low => {
+                        *on = !*on;
+                        Some(if *on { Pulse::High } else { Pulse::Low })
+                    }
+                },
+                Kind::Conjunction { memory } => {
+                    for (input, last) in memory.iter_mut() {
+                        if *input == signal.from {
+                            *last = signal.pulse;
+                        }
+                    }
+                    match memory.iter().all(|&(_, last)| last == Pulse::High) {
+                        true => Some(Pulse::Low),
+                        false => Some(Pulse::High),
+                    }
+                }
+                Kind::Untyped => None,
+            };
+
+            if let Some(pulse) = pulse {
+                queue.extend(module.destinations.iter().map(|&to| Signal {
+                    from: signal.to,
+                    to,
+                    pulse,
+                }));
+            }
+        }
+
+        counts
+    }
+
+    /// Pulses sent over `presses` presses of the button.
+    pub fn press_many(&mut self, presses: u64) -> Counts {
+        (0..presses).fold(Counts::default(), |total, _| {
+            let counts = self.press(|_| {});
+            Counts {
+                low: total.low + counts.low,
+                high: total.high + counts.high,
+            }
+        })
+    }
+
+    /// The fewest presses from a fresh circuit before `target` is sent a low
+    /// pulse, found without pressing anywhere near that many times.
+    ///
+    /// Like the puzzle inputs, `target` has to be fed by a single conjunction
+    /// whose inputs each send it a high pulse on a fixed cycle of presses.
+    /// The conjunction then sends a low pulse on the first press all of
+    /// those cycles line up.
+    pub fn presses_until_low(&mut self, target: &str) -> Option<u64> {
+        let [hub] = self.inputs(self.id(target)?)[..] else {
+            return None;
+        };
+        let Kind::Conjunction { memory } = &self.modules[hub.0].kind else {
+            return None;
+        };
+        let inputs = memory.iter().map(|&(input, _)| input).collect::<Vec<_>>();
+
+        // The first two presses each input goes high on give its cycle
+        self.reset();
+        let mut highs = vec![vec![]; inputs.len()];
+        for presses in 1..=MAX_PRESSES {
+            self.press(|signal| {
+                if signal.to != hub || signal.pulse != Pulse::High {
+                    return;
+                }
+                let input = inputs.iter().position(|&input| input == signal.from);
+                if let Some(times) = input.map(|input| &mut highs[input]) {
+                    if times.last() != Some(&presses) {
+                        times.push(presses);
+                    }
+                }
+            });
+
+            if highs.iter().all(|times| times.len() >= 2) {
+                break;
+            }
+        }
+        self.reset();
+
+        let cycles = highs
+            .iter()
+            .map(|times| match times[..] {
+                [first, second, ..] => Some((first as i128, (second - first) as i128)),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        // Every input has to be past its first high pulse too
+        let earliest = cycles.iter().map(|&(first, _)| first).max()?;
+        let (press, period) = crt(cycles.iter().map(|&(first, len)| (first % len, len)))?;
+        let behind = (earliest - press).max(0);
+        Some((press + (behind + period - 1) / period * period) as u64)
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed = Circuit;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Circuit::from(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_press() {
+        let input = r#"broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a"#;
+
+        let mut circuit = Circuit::from(input);
+        let mut sent = vec![];
+        let counts = circuit.press(|&signal| sent.push(signal));
+        let sent = sent
+            .iter()
+            .map(|signal| {
+                let (from, to) = (circuit.name(signal.from), circuit.name(signal.to));
+                format!("{from} -{:?}-> {to}", signal.pulse)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            sent,
+            vec![
+                "button -Low-> broadcaster",
+                "broadcaster -Low-> a",
+                "broadcaster -Low-> b",
+                "broadcaster -Low-> c",
+                "a -High-> b",
+                "b -High-> c",
+                "c -High-> inv",
+                "inv -Low-> a",
+                "a -Low-> b",
+                "b -Low-> c",
+                "c -Low-> inv",
+                "inv -High-> a",
+            ]
+        );
+        assert_eq!(counts, Counts { low: 8, high: 4 });
+    }
+
+    #[test]
+    fn examples() {
+        let input = r#"broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a"#;
+
+        let counts = Circuit::from(input).press_many(1000);
+        assert_eq!(counts.low * counts.high, 32000000);
+        assert_eq!(part1::solve(&Day20::parse(input)), 32000000);
+
+        let input = r#"broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output"#;
+
+        let mut circuit = Circuit::from(input);
+        let counts = circuit.press_many(1000);
+        assert_eq!(counts.low * counts.high, 11687500);
+        assert_eq!(
+            circuit.name(circuit.inputs(circuit.id("output").unwrap())[0]),
+            "con"
+        );
+    }
+
+    #[test]
+    fn counters() {
+        // Two three bit counters, one resetting after 7 presses and one after
+        // 5, both feeding the hub in front of rx
+        let input = r#"broadcaster -> a0, b0
+%a0 -> a1, ca
+%a1 -> a2, ca
+%a2 -> ca
+&ca -> a0, ia
+&ia -> hub
+%b0 -> b1, cb
+%b1 -> b2
+%b2 -> cb
+&cb -> b0, b1, ib
+&ib -> hub
+&hub -> rx"#;
+
+        let mut circuit = Circuit::from(input);
+        let rx = circuit.id("rx").unwrap();
+        let brute = (1..=100).find(|_| {
+            let mut low = false;
+            circuit.press(|signal| low |= signal.to == rx && signal.pulse == Pulse::Low);
+            low
+        });
+
+        assert_eq!(brute, Some(35));
+        assert_eq!(circuit.presses_until_low("rx"), brute);
+        assert_eq!(part2::solve(&Day20::parse(input)), brute);
+        assert_eq!(circuit.presses_until_low("output"), None);
+    }
+}
@@ -0,0 +1,6 @@
+use crate::Circuit;
+
+pub fn solve(circuit: &Circuit) -> u64 {
+    let counts = circuit.clone().press_many(1000);
+    counts.low * counts.high
+}
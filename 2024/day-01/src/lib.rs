@@ -0,0 +1,43 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// The left and right location ids from each line
+    type Parsed = Vec<(u32, u32)>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(|line| {
+                let (left, right) = line.split_once("  ").unwrap();
+                (
+                    left.trim().parse::<u32>().unwrap(),
+                    right.trim().parse::<u32>().unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        utils::check_examples(&Day01, 2024, 1);
+    }
+}
@@ -1,7 +0,0 @@
-mod part1;
-mod part2;
-
-fn main() {
-    println!("Part 1 answer: {}", part1::solve());
-    println!("Part 2 answer: {}", part2::solve());
-}
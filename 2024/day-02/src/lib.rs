@@ -0,0 +1,30 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    /// One report of levels per line
+    type Parsed = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input
+            .lines()
+            .map(|line| {
+                line.split(" ")
+                    .map(|num| num.parse::<u32>().unwrap())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
@@ -17,13 +17,13 @@ impl LevelChange {
     }
 }
 
-fn is_report_safe(data: Vec<u32>) -> bool {
+fn is_report_safe(data: &[u32]) -> bool {
     let mut level_change: Option<LevelChange> = None;
 
     for window in data.windows(2) {
         let diff = window[0].abs_diff(window[1]);
 
-        if diff > 3 || diff < 1 {
+        if !(1..=3).contains(&diff) {
             return false;
         }
 
@@ -54,23 +54,9 @@ fn is_report_safe(data: Vec<u32>) -> bool {
     }
 }
 
-pub fn solve() -> u32 {
-    let safe_count = include_str!("../input/part1.txt")
-        .lines()
-        .into_iter()
-        .map(|line| {
-            let nums = line
-                .split(" ")
-                .map(|num| u32::from_str_radix(num, 10).unwrap())
-                .collect::<Vec<_>>();
-
-            if is_report_safe(nums) {
-                1
-            } else {
-                0
-            }
-        })
-        .sum();
-
-    safe_count
+pub fn solve(reports: &[Vec<u32>]) -> u32 {
+    reports
+        .iter()
+        .map(|nums| if is_report_safe(nums) { 1 } else { 0 })
+        .sum()
 }
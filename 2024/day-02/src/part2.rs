@@ -17,13 +17,13 @@ impl LevelChange {
     }
 }
 
-fn is_report_safe(data: &Vec<u32>) -> bool {
+fn is_report_safe(data: &[u32]) -> bool {
     let mut level_change: Option<LevelChange> = None;
 
     for window in data.windows(2) {
         let diff = window[0].abs_diff(window[1]);
 
-        if diff > 3 || diff < 1 {
+        if !(1..=3).contains(&diff) {
             return false;
         }
 
@@ -50,32 +50,23 @@ fn is_report_safe(data: &Vec<u32>) -> bool {
     true
 }
 
-pub fn solve() -> u32 {
-    let safe_count = include_str!("../input/part1.txt")
-        .lines()
-        .map(|line| {
-            let nums: Vec<u32> = line
-                .split(" ")
-                .map(|num| num.parse::<u32>().unwrap())
-                .collect();
+pub fn solve(reports: &[Vec<u32>]) -> u32 {
+    reports
+        .iter()
+        .map(|nums| match is_report_safe(nums) {
+            true => 1,
+            false => {
+                for i in 0..nums.len() {
+                    let mut modified_nums = nums.clone();
+                    modified_nums.remove(i);
 
-            match is_report_safe(&nums) {
-                true => 1,
-                false => {
-                    for i in 0..nums.len() {
-                        let mut modified_nums = nums.clone();
-                        modified_nums.remove(i);
-
-                        if is_report_safe(&modified_nums) {
-                            return 1;
-                        }
+                    if is_report_safe(&modified_nums) {
+                        return 1;
                     }
-
-                    0
                 }
+
+                0
             }
         })
-        .sum();
-
-    safe_count
+        .sum()
 }
@@ -0,0 +1,70 @@
+use regex::Regex;
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug)]
+pub struct Mul {
+    x: u32,
+    y: u32,
+}
+
+impl Mul {
+    fn new_from_str(str: &str) -> Self {
+        let mut stripped = str.chars();
+        stripped.next();
+        stripped.next();
+        stripped.next();
+        stripped.next();
+        stripped.next_back();
+        let (x, y) = stripped.as_str().split_once(",").unwrap();
+
+        Self {
+            x: x.parse::<u32>().unwrap(),
+            y: y.parse::<u32>().unwrap(),
+        }
+    }
+
+    pub fn multiply(&self) -> u32 {
+        self.x * self.y
+    }
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Mul(Mul),
+    DoNot,
+    Do,
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Vec<Instruction>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        let regex = Regex::new(r"(mul\(\d{1,3},\d{1,3}\))|(don't\(\))|(do\(\))").unwrap();
+
+        regex
+            .captures_iter(input)
+            .map(|cap| {
+                if let Some(mul) = cap.get(1) {
+                    Instruction::Mul(Mul::new_from_str(mul.as_str()))
+                } else if cap.get(2).is_some() {
+                    Instruction::DoNot
+                } else {
+                    Instruction::Do
+                }
+            })
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
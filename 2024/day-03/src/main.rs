@@ -1,7 +0,0 @@
-mod part1;
-mod part2;
-
-fn main() {
-    println!("Part 1: {}", part1::solve());
-    println!("Part 2: {}", part2::solve());
-}
@@ -0,0 +1,64 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Eq, PartialEq, Debug)]
+pub enum XmasLetter {
+    X,
+    M,
+    A,
+    S,
+    Unknown,
+}
+
+impl From<char> for XmasLetter {
+    fn from(value: char) -> Self {
+        match value {
+            'X' => Self::X,
+            'M' => Self::M,
+            'A' => Self::A,
+            'S' => Self::S,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+pub struct Puzzle {
+    width: usize,
+    height: usize,
+    lines: Vec<Vec<XmasLetter>>,
+}
+
+impl Puzzle {
+    pub fn new(str: &str) -> Self {
+        let lines = str
+            .lines()
+            .map(|line| line.chars().map(XmasLetter::from).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        Self {
+            width: lines.first().unwrap().len(),
+            height: lines.len(),
+            lines,
+        }
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Puzzle;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Puzzle::new(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
@@ -1,23 +1,4 @@
-#[derive(Eq, PartialEq, Debug)]
-enum XmasLetter {
-    X,
-    M,
-    A,
-    S,
-    Unknown,
-}
-
-impl From<char> for XmasLetter {
-    fn from(value: char) -> Self {
-        match value {
-            'X' => Self::X,
-            'M' => Self::M,
-            'A' => Self::A,
-            'S' => Self::S,
-            _ => Self::Unknown,
-        }
-    }
-}
+use crate::{Puzzle, XmasLetter};
 
 #[derive(Debug)]
 struct Position {
@@ -31,26 +12,7 @@ impl Position {
     }
 }
 
-struct Puzzle {
-    width: usize,
-    height: usize,
-    lines: Vec<Vec<XmasLetter>>,
-}
-
 impl Puzzle {
-    fn new(str: &str) -> Self {
-        let lines = str
-            .lines()
-            .map(|line| line.chars().map(XmasLetter::from).collect::<Vec<_>>())
-            .collect::<Vec<_>>();
-
-        Self {
-            width: lines.first().unwrap().len(),
-            height: lines.len(),
-            lines,
-        }
-    }
-
     fn is_letter_at_pos(&self, pos: &Position, letter: &XmasLetter) -> bool {
         match self.lines.get(pos.row).and_then(|line| line.get(pos.col)) {
             Some(l) => letter == l,
@@ -166,7 +128,7 @@ impl Puzzle {
         let mut count = 0;
 
         // Up
-        if self.is_pos_valid_to_top(&pos)
+        if self.is_pos_valid_to_top(pos)
             && self.do_positions_spell_xmas(&[
                 &Position::new(pos.row - 1, pos.col),
                 &Position::new(pos.row - 2, pos.col),
@@ -191,20 +153,15 @@ impl Puzzle {
     }
 }
 
-pub fn solve() -> u32 {
-    let puzzle = Puzzle::new(include_str!("../input/part1.txt"));
-
+pub fn solve(puzzle: &Puzzle) -> u32 {
     let mut xmas_count = 0;
     for (line_num, line) in puzzle.lines.iter().enumerate() {
         for (letter_num, letter) in line.iter().enumerate() {
-            match letter {
-                XmasLetter::X => {
-                    let pos = Position::new(line_num, letter_num);
-                    xmas_count += puzzle.count_diagonal(&pos);
-                    xmas_count += puzzle.count_horizontal(&pos);
-                    xmas_count += puzzle.count_vertical(&pos);
-                }
-                _ => {}
+            if letter == &XmasLetter::X {
+                let pos = Position::new(line_num, letter_num);
+                xmas_count += puzzle.count_diagonal(&pos);
+                xmas_count += puzzle.count_horizontal(&pos);
+                xmas_count += puzzle.count_vertical(&pos);
             }
         }
     }
@@ -215,9 +172,8 @@ pub fn solve() -> u32 {
 #[cfg(test)]
 mod tests {
     // These tests are not good and complete, only test very happy paths
-    use crate::part1::Position;
-
-    use super::Puzzle;
+    use super::Position;
+    use crate::Puzzle;
 
     #[test]
     fn diagonal_works() {
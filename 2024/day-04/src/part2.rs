@@ -1,21 +1,4 @@
-#[derive(Eq, PartialEq, Debug)]
-enum XmasLetter {
-    M,
-    A,
-    S,
-    Unknown,
-}
-
-impl From<char> for XmasLetter {
-    fn from(value: char) -> Self {
-        match value {
-            'M' => Self::M,
-            'A' => Self::A,
-            'S' => Self::S,
-            _ => Self::Unknown,
-        }
-    }
-}
+use crate::{Puzzle, XmasLetter};
 
 #[derive(Eq, PartialEq)]
 enum Direction {
@@ -58,26 +41,7 @@ impl Position {
     }
 }
 
-struct Grid {
-    width: usize,
-    height: usize,
-    lines: Vec<Vec<XmasLetter>>,
-}
-
-impl Grid {
-    fn new(str: &str) -> Self {
-        let lines = str
-            .lines()
-            .map(|line| line.chars().map(XmasLetter::from).collect::<Vec<_>>())
-            .collect::<Vec<_>>();
-
-        Self {
-            width: lines.first().unwrap().len(),
-            height: lines.len(),
-            lines,
-        }
-    }
-
+impl Puzzle {
     fn contains_position(&self, pos: &Position) -> bool {
         pos.row >= 0
             && (pos.row as usize) < self.height
@@ -90,7 +54,7 @@ impl Grid {
             true => self
                 .lines
                 .get(pos.row as usize)
-                .map_or(None, |line| line.get(pos.col as usize)),
+                .and_then(|line| line.get(pos.col as usize)),
             false => None,
         }
     }
@@ -102,33 +66,28 @@ impl Grid {
             self.try_get(&pos.new_in_direction(&Direction::NorthWest)),
             self.try_get(&pos.new_in_direction(&Direction::SouthEast)),
         ) {
-            match (ne, sw, nw, se) {
+            matches!(
+                (ne, sw, nw, se),
                 (XmasLetter::M, XmasLetter::S, XmasLetter::M, XmasLetter::S)
-                | (XmasLetter::M, XmasLetter::S, XmasLetter::S, XmasLetter::M)
-                | (XmasLetter::S, XmasLetter::M, XmasLetter::M, XmasLetter::S)
-                | (XmasLetter::S, XmasLetter::M, XmasLetter::S, XmasLetter::M) => true,
-                _ => false,
-            }
+                    | (XmasLetter::M, XmasLetter::S, XmasLetter::S, XmasLetter::M)
+                    | (XmasLetter::S, XmasLetter::M, XmasLetter::M, XmasLetter::S)
+                    | (XmasLetter::S, XmasLetter::M, XmasLetter::S, XmasLetter::M)
+            )
         } else {
             false
         }
     }
 }
 
-pub fn solve() -> u32 {
-    let puzzle = Grid::new(include_str!("../input/part1.txt"));
-
+pub fn solve(puzzle: &Puzzle) -> u32 {
     let mut xmas_count = 0;
     for (line_num, line) in puzzle.lines.iter().enumerate() {
         for (letter_num, letter) in line.iter().enumerate() {
-            match letter {
-                XmasLetter::A => {
-                    if puzzle.is_xmas(&Position::new(line_num as i32, letter_num as i32)) {
-                        xmas_count += 1;
-                    };
-                }
-                _ => {}
-            }
+            if letter == &XmasLetter::A
+                && puzzle.is_xmas(&Position::new(line_num as i32, letter_num as i32))
+            {
+                xmas_count += 1;
+            };
         }
     }
 
@@ -0,0 +1,76 @@
+use std::collections::{HashMap, HashSet};
+
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub type PageOrderRules = HashMap<u32, Vec<u32>>;
+pub type PageNumbers = Vec<u32>;
+
+fn to_u32(str: &str) -> u32 {
+    str.parse::<u32>().unwrap()
+}
+
+pub fn parse_input(str: &str) -> (Vec<PageNumbers>, PageOrderRules) {
+    let (rules, numbers) = str.split_once("\n\n").expect("Valid input");
+
+    let rules: PageOrderRules = rules.split("\n").take_while(|line| !line.is_empty()).fold(
+        HashMap::new(),
+        |mut map, line| {
+            let (first, second) = line.split_once("|").expect("Valid line");
+            let entry = map.entry(to_u32(first)).or_insert(vec![]);
+            entry.push(to_u32(second));
+
+            map
+        },
+    );
+
+    let numbers: Vec<PageNumbers> = numbers
+        .split("\n")
+        .take_while(|line| !line.is_empty())
+        .map(|line| {
+            line.split(",")
+                .map(|num| num.parse::<u32>().unwrap())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    (numbers, rules)
+}
+
+pub fn is_row_valid(page_numbers: &PageNumbers, page_order_rules: &PageOrderRules) -> bool {
+    let mut seen_numbers: HashSet<&u32> = HashSet::new();
+
+    for page_number in page_numbers {
+        if let Some(p) = page_order_rules.get(page_number) {
+            // If we have already seen a number that should come after us,
+            // the row is invalid
+            if p.iter().any(|num| seen_numbers.contains(num)) {
+                return false;
+            }
+        }
+
+        seen_numbers.insert(page_number);
+    }
+
+    true
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = (Vec<PageNumbers>, PageOrderRules);
+
+    fn parse(input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
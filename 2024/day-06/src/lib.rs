@@ -0,0 +1,145 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Eq, PartialEq, Clone, Hash, Debug, Copy)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn turn_90_deg(&self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+}
+
+#[derive(Eq, PartialEq)]
+pub enum GridObject {
+    Guard,
+    Obstruction,
+    Empty,
+}
+
+impl From<char> for GridObject {
+    fn from(value: char) -> Self {
+        match value {
+            '#' => Self::Obstruction,
+            '^' => Self::Guard,
+            '.' => Self::Empty,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Position {
+    row: isize,
+    col: isize,
+}
+
+impl Position {
+    fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    fn move_in_direction(&self, dir: &Direction) -> Self {
+        match dir {
+            Direction::Up => Self {
+                row: self.row - 1,
+                col: self.col,
+            },
+            Direction::Down => Self {
+                row: self.row + 1,
+                col: self.col,
+            },
+            Direction::Left => Self {
+                row: self.row,
+                col: self.col - 1,
+            },
+            Direction::Right => Self {
+                row: self.row,
+                col: self.col + 1,
+            },
+        }
+    }
+}
+
+pub struct PuzzleMap {
+    height: usize,
+    width: usize,
+    start_pos: Position,
+    items: Vec<Vec<GridObject>>,
+}
+
+impl PuzzleMap {
+    pub fn new(str: &str) -> Self {
+        let mut start_pos = None;
+        let items = str
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(col, c)| {
+                        let obj = GridObject::from(c);
+                        if obj == GridObject::Guard {
+                            start_pos = Some(Position::new(row as isize, col as isize));
+                        };
+                        obj
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            width: items.first().unwrap().len(),
+            height: items.len(),
+            start_pos: start_pos.unwrap(),
+            items,
+        }
+    }
+
+    fn contains_position(&self, pos: &Position) -> bool {
+        pos.row >= 0
+            && (pos.row as usize) < self.height
+            && pos.col >= 0
+            && (pos.col as usize) < self.width
+    }
+
+    fn try_get(&self, pos: &Position) -> Option<&GridObject> {
+        match self.contains_position(pos) {
+            true => self
+                .items
+                .get(pos.row as usize)
+                .and_then(|line| line.get(pos.col as usize)),
+            false => None,
+        }
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = PuzzleMap;
+
+    fn parse(input: &str) -> Self::Parsed {
+        PuzzleMap::new(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
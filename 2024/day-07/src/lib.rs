@@ -0,0 +1,50 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug)]
+pub struct Equation {
+    test_value: u64,
+    numbers: Vec<u64>,
+}
+
+impl From<&str> for Equation {
+    fn from(value: &str) -> Self {
+        let (test_value, numbers) = value.split_once(":").unwrap();
+        let test_value = test_value.parse::<u64>().unwrap();
+        let numbers = numbers
+            .trim()
+            .split(" ")
+            .take_while(|str| !str.is_empty())
+            .map(|num| num.parse::<u64>().unwrap())
+            .collect::<Vec<_>>();
+
+        Self {
+            test_value,
+            numbers,
+        }
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = Vec<Equation>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(Equation::from)
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
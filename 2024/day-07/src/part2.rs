@@ -1,6 +1,8 @@
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
+use crate::Equation;
+
 #[derive(Debug, EnumIter)]
 enum Operation {
     Multiply,
@@ -10,41 +12,17 @@ enum Operation {
 
 impl Operation {
     fn apply(&self, left: u64, right: u64) -> u64 {
-        match self {
-            &Self::Multiply => left * right,
-            &Self::Add => left + right,
-            &Self::Concatenation => left * 10u64.pow(right.ilog10() + 1) + right,
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Equation {
-    test_value: u64,
-    numbers: Vec<u64>,
-}
-
-impl From<&str> for Equation {
-    fn from(value: &str) -> Self {
-        let (test_value, numbers) = value.split_once(":").unwrap();
-        let test_value = u64::from_str_radix(test_value, 10).unwrap();
-        let numbers = numbers
-            .trim()
-            .split(" ")
-            .take_while(|str| !str.is_empty())
-            .map(|num| u64::from_str_radix(num, 10).unwrap())
-            .collect::<Vec<_>>();
-
-        Self {
-            test_value,
-            numbers,
+        match *self {
+            Self::Multiply => left * right,
+            Self::Add => left + right,
+            Self::Concatenation => left * 10u64.pow(right.ilog10() + 1) + right,
         }
     }
 }
 
 impl Equation {
-    fn is_valid(&self) -> bool {
-        if self.numbers.len() == 0 {
+    fn is_valid_with_concatenation(&self) -> bool {
+        if self.numbers.is_empty() {
             return false;
         }
 
@@ -77,22 +55,24 @@ impl Equation {
     }
 }
 
-fn calibrate(input: &str) -> u64 {
-    input
-        .lines()
-        .take_while(|line| !line.is_empty())
-        .map(Equation::from)
-        .map(|eq| if eq.is_valid() { eq.test_value } else { 0 })
+pub fn solve(equations: &[Equation]) -> u64 {
+    equations
+        .iter()
+        .map(|eq| {
+            if eq.is_valid_with_concatenation() {
+                eq.test_value
+            } else {
+                0
+            }
+        })
         .sum()
 }
 
-pub fn solve() -> u64 {
-    calibrate(include_str!("../input/part1.txt"))
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day07;
+    use utils::Solution;
 
     #[test]
     fn test_sol() {
@@ -106,6 +86,6 @@ mod tests {
 21037: 9 7 18 13
 292: 11 6 16 20"#;
 
-        assert_eq!(calibrate(input), 11387);
+        assert_eq!(solve(&Day07::parse(input)), 11387);
     }
 }
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub type Frequency = char;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum MapObject {
+    Antenna(Frequency),
+    Empty,
+}
+
+impl From<char> for MapObject {
+    fn from(value: char) -> Self {
+        match value {
+            '0'..='9' | 'A'..='Z' | 'a'..='z' => Self::Antenna(value),
+            _ => Self::Empty,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Position {
+    row: isize,
+    col: isize,
+}
+
+impl Position {
+    fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    fn difference(&self, other: &Self) -> Self {
+        Self {
+            row: other.row - self.row,
+            col: other.col - self.col,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct AntennaGrid {
+    width: usize,
+    height: usize,
+    items: Vec<Vec<MapObject>>,
+    freq_positions: HashMap<Frequency, Vec<Position>>,
+}
+
+impl From<&str> for AntennaGrid {
+    fn from(value: &str) -> Self {
+        let mut freq_positions = HashMap::new();
+        let items = value
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(col, freq)| {
+                        let entry = freq_positions.entry(freq).or_insert(Vec::new());
+                        entry.push(Position::new(row as isize, col as isize));
+
+                        MapObject::from(freq)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            width: items.first().map_or(0, |line| line.len()),
+            height: items.len(),
+            items,
+            freq_positions,
+        }
+    }
+}
+
+impl AntennaGrid {
+    fn contains_position(&self, pos: &Position) -> bool {
+        pos.row >= 0
+            && (pos.row as usize) < self.height
+            && pos.col >= 0
+            && (pos.col as usize) < self.width
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed = AntennaGrid;
+
+    fn parse(input: &str) -> Self::Parsed {
+        AntennaGrid::from(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
@@ -0,0 +1,47 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// Each block holds the id of the file stored in it, or `None` when free
+#[derive(Clone, Debug)]
+pub struct DiskMap {
+    data: Vec<Option<usize>>,
+}
+
+impl From<&str> for DiskMap {
+    fn from(value: &str) -> Self {
+        let data = value
+            .chars()
+            .enumerate()
+            .flat_map(|(idx, c)| {
+                if let Some(num) = c.to_digit(10) {
+                    std::iter::repeat(if idx & 1 == 0 { Some(idx / 2) } else { None })
+                        .take(num as usize)
+                } else {
+                    std::iter::repeat(Some(0)).take(0)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Self { data }
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = DiskMap;
+
+    fn parse(input: &str) -> Self::Parsed {
+        DiskMap::from(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
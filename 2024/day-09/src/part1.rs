@@ -1,41 +1,4 @@
-#[derive(Debug, Eq, PartialEq)]
-enum DiskItem {
-    File(u64),
-    FreeSpace,
-}
-
-#[derive(Debug)]
-struct DiskMap {
-    data: Vec<DiskItem>,
-}
-
-impl From<&str> for DiskMap {
-    fn from(value: &str) -> Self {
-        let data = value
-            .chars()
-            .enumerate()
-            .fold(Vec::new(), |mut vec, (idx, c)| {
-                // Could probably be a smarter nicer way of doing this,
-                // but eh this is fine for now : )
-                if let Some(num) = c.to_digit(10) {
-                    if idx & 1 == 0 {
-                        let file_id: u64 = (idx / 2) as u64;
-                        for _ in 0..num {
-                            vec.push(DiskItem::File(file_id));
-                        }
-                    } else {
-                        for _ in 0..num {
-                            vec.push(DiskItem::FreeSpace);
-                        }
-                    };
-                }
-
-                vec
-            });
-
-        Self { data }
-    }
-}
+use crate::DiskMap;
 
 impl DiskMap {
     fn find_next_file_idx(&self, start_idx: usize) -> usize {
@@ -46,7 +9,7 @@ impl DiskMap {
             .enumerate()
             .skip_while(|(idx, _)| self.data.len() - 1 - idx > start_idx)
         {
-            if item != &DiskItem::FreeSpace {
+            if item.is_some() {
                 return self.data.len() - 1 - idx;
             }
         }
@@ -61,7 +24,7 @@ impl DiskMap {
             .enumerate()
             .skip_while(|(idx, _)| idx < &start_idx)
         {
-            if item == &DiskItem::FreeSpace {
+            if item.is_none() {
                 return idx;
             }
         }
@@ -82,17 +45,13 @@ impl DiskMap {
         self.data
             .iter()
             .enumerate()
-            .map(|(idx, item)| match item {
-                DiskItem::File(id) => *id * idx as u64,
-                _ => 0,
-            })
+            .filter_map(|(idx, id)| id.map(|id| (id * idx) as u64))
             .sum()
     }
 }
 
-pub fn solve() -> u64 {
-    let input = include_str!("../input/part1.txt");
-    DiskMap::from(input).move_files_to_start()
+pub fn solve(disk: &DiskMap) -> u64 {
+    disk.clone().move_files_to_start()
 }
 
 #[cfg(test)]
@@ -1,27 +1,4 @@
-#[derive(Debug)]
-struct DiskMap {
-    data: Vec<Option<usize>>,
-}
-
-impl From<&str> for DiskMap {
-    fn from(value: &str) -> Self {
-        let data = value
-            .chars()
-            .enumerate()
-            .map(|(idx, c)| {
-                if let Some(num) = c.to_digit(10) {
-                    std::iter::repeat(if idx & 1 == 0 { Some(idx / 2) } else { None })
-                        .take(num as usize)
-                } else {
-                    std::iter::repeat(Some(0)).take(0)
-                }
-            })
-            .flatten()
-            .collect::<Vec<_>>();
-
-        Self { data }
-    }
-}
+use crate::DiskMap;
 
 impl DiskMap {
     fn checksum(&mut self) -> u64 {
@@ -56,9 +33,7 @@ impl DiskMap {
                 let (left, right) = self.data.split_at_mut(start_chunk_idx);
                 left[slot..(slot + chunk_size)].copy_from_slice(&right[..chunk_size]);
 
-                for i in 0..chunk_size {
-                    right[i] = None;
-                }
+                right[..chunk_size].fill(None);
             }
 
             idx = start_chunk_idx;
@@ -72,8 +47,8 @@ impl DiskMap {
     }
 }
 
-pub fn solve() -> u64 {
-    DiskMap::from(include_str!("../input/part1.txt")).checksum()
+pub fn solve(disk: &DiskMap) -> u64 {
+    disk.clone().checksum()
 }
 
 #[cfg(test)]
@@ -0,0 +1,120 @@
+use strum_macros::EnumIter;
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(EnumIter)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub struct Position {
+    row: isize,
+    col: isize,
+}
+
+impl Position {
+    fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    fn move_in_dir(&self, dir: &Direction) -> Self {
+        match dir {
+            Direction::Up => Self {
+                row: self.row - 1,
+                col: self.col,
+            },
+            Direction::Down => Self {
+                row: self.row + 1,
+                col: self.col,
+            },
+            Direction::Left => Self {
+                row: self.row,
+                col: self.col - 1,
+            },
+            Direction::Right => Self {
+                row: self.row,
+                col: self.col + 1,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Height(u8);
+
+impl From<char> for Height {
+    fn from(value: char) -> Self {
+        Self(value.to_digit(10).unwrap() as u8)
+    }
+}
+
+impl Height {
+    fn is_trailhead(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+pub struct LavaMap {
+    height: usize,
+    width: usize,
+    heights: Vec<Vec<Height>>,
+}
+
+impl From<&str> for LavaMap {
+    fn from(value: &str) -> Self {
+        let heights = value
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(|line| line.chars().map(Height::from).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        Self {
+            height: heights.len(),
+            width: heights[0].len(),
+            heights,
+        }
+    }
+}
+
+impl LavaMap {
+    fn contains_position(&self, pos: &Position) -> bool {
+        pos.row >= 0
+            && (pos.row as usize) < self.height
+            && pos.col >= 0
+            && (pos.col as usize) < self.width
+    }
+
+    fn try_get(&self, pos: &Position) -> Option<&Height> {
+        match self.contains_position(pos) {
+            true => self
+                .heights
+                .get(pos.row as usize)
+                .and_then(|line| line.get(pos.col as usize)),
+            false => None,
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = LavaMap;
+
+    fn parse(input: &str) -> Self::Parsed {
+        LavaMap::from(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
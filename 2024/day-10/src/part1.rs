@@ -1,129 +1,24 @@
-use std::collections::HashSet;
 use strum::IntoEnumIterator;
-use strum_macros::EnumIter;
+use utils::search;
 
-#[derive(EnumIter)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
-struct Position {
-    row: isize,
-    col: isize,
-}
-
-impl Position {
-    fn new(row: isize, col: isize) -> Self {
-        Self { row, col }
-    }
-
-    fn move_in_dir(&self, dir: &Direction) -> Self {
-        match dir {
-            Direction::Up => Self {
-                row: self.row - 1,
-                col: self.col,
-            },
-            Direction::Down => Self {
-                row: self.row + 1,
-                col: self.col,
-            },
-            Direction::Left => Self {
-                row: self.row,
-                col: self.col - 1,
-            },
-            Direction::Right => Self {
-                row: self.row,
-                col: self.col + 1,
-            },
-        }
-    }
-}
-
-#[derive(Clone, Copy)]
-struct Height(u8);
-
-impl From<char> for Height {
-    fn from(value: char) -> Self {
-        Self(value.to_digit(10).unwrap() as u8)
-    }
-}
-
-impl Height {
-    fn is_trailhead(&self) -> bool {
-        self.0 == 0
-    }
-}
-
-struct LavaMap {
-    height: usize,
-    width: usize,
-    heights: Vec<Vec<Height>>,
-}
-
-impl From<&str> for LavaMap {
-    fn from(value: &str) -> Self {
-        let heights = value
-            .lines()
-            .take_while(|line| !line.is_empty())
-            .map(|line| line.chars().map(Height::from).collect::<Vec<_>>())
-            .collect::<Vec<_>>();
-
-        Self {
-            height: heights.len(),
-            width: heights[0].len(),
-            heights,
-        }
-    }
-}
+use crate::{Direction, LavaMap, Position};
 
 impl LavaMap {
-    fn contains_position(&self, pos: &Position) -> bool {
-        pos.row >= 0
-            && (pos.row as usize) < self.height
-            && pos.col >= 0
-            && (pos.col as usize) < self.width
-    }
-
-    fn try_get(&self, pos: &Position) -> Option<&Height> {
-        match self.contains_position(pos) {
-            true => self
-                .heights
-                .get(pos.row as usize)
-                .map_or(None, |line| line.get(pos.col as usize)),
-            false => None,
-        }
-    }
-
     fn score_trailhead(&self, pos: &Position) -> u32 {
-        let mut seen = HashSet::new();
+        let reached = search::bfs(*pos, |pos| {
+            let height = self.try_get(pos).map_or(0, |height| height.0);
 
-        Direction::iter().for_each(|dir| {
-            self.rec(pos.move_in_dir(&dir), 1, &mut seen);
+            Direction::iter()
+                .map(|dir| pos.move_in_dir(&dir))
+                .filter(|next| self.try_get(next).is_some_and(|next| next.0 == height + 1))
+                .collect::<Vec<_>>()
         });
 
-        seen.len() as u32
-    }
-
-    fn rec(&self, pos: Position, h: u8, seen: &mut HashSet<Position>) {
-        let height = match self.try_get(&pos) {
-            Some(height) => height,
-            None => return,
-        };
-
-        if height.0 != h {
-            return;
-        };
-
-        if height.0 == 9 {
-            seen.insert(pos);
-            return;
-        };
-
-        Direction::iter().for_each(|dir| self.rec(pos.move_in_dir(&dir), height.0 + 1, seen));
+        reached
+            .distances
+            .keys()
+            .filter(|pos| self.try_get(pos).is_some_and(|height| height.0 == 9))
+            .count() as u32
     }
 
     fn total_trailhead_score(&self) -> u32 {
@@ -145,8 +40,8 @@ impl LavaMap {
     }
 }
 
-pub fn solve() -> u32 {
-    LavaMap::from(include_str!("../input/part1.txt")).total_trailhead_score()
+pub fn solve(map: &LavaMap) -> u32 {
+    map.total_trailhead_score()
 }
 
 #[cfg(test)]
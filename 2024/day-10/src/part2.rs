@@ -1,109 +1,15 @@
 use strum::IntoEnumIterator;
-use strum_macros::EnumIter;
 
-#[derive(EnumIter)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
-struct Position {
-    row: isize,
-    col: isize,
-}
-
-impl Position {
-    fn new(row: isize, col: isize) -> Self {
-        Self { row, col }
-    }
-
-    fn move_in_dir(&self, dir: &Direction) -> Self {
-        match dir {
-            Direction::Up => Self {
-                row: self.row - 1,
-                col: self.col,
-            },
-            Direction::Down => Self {
-                row: self.row + 1,
-                col: self.col,
-            },
-            Direction::Left => Self {
-                row: self.row,
-                col: self.col - 1,
-            },
-            Direction::Right => Self {
-                row: self.row,
-                col: self.col + 1,
-            },
-        }
-    }
-}
-
-#[derive(Clone, Copy)]
-struct Height(u8);
-
-impl From<char> for Height {
-    fn from(value: char) -> Self {
-        Self(value.to_digit(10).unwrap() as u8)
-    }
-}
-
-impl Height {
-    fn is_trailhead(&self) -> bool {
-        self.0 == 0
-    }
-}
-
-struct LavaMap {
-    height: usize,
-    width: usize,
-    heights: Vec<Vec<Height>>,
-}
-
-impl From<&str> for LavaMap {
-    fn from(value: &str) -> Self {
-        let heights = value
-            .lines()
-            .take_while(|line| !line.is_empty())
-            .map(|line| line.chars().map(Height::from).collect::<Vec<_>>())
-            .collect::<Vec<_>>();
-
-        Self {
-            height: heights.len(),
-            width: heights[0].len(),
-            heights,
-        }
-    }
-}
+use crate::{Direction, LavaMap, Position};
 
 impl LavaMap {
-    fn contains_position(&self, pos: &Position) -> bool {
-        pos.row >= 0
-            && (pos.row as usize) < self.height
-            && pos.col >= 0
-            && (pos.col as usize) < self.width
-    }
-
-    fn try_get(&self, pos: &Position) -> Option<&Height> {
-        match self.contains_position(pos) {
-            true => self
-                .heights
-                .get(pos.row as usize)
-                .map_or(None, |line| line.get(pos.col as usize)),
-            false => None,
-        }
-    }
-
-    fn score_trailhead(&self, pos: &Position) -> u32 {
+    fn rate_trailhead(&self, pos: &Position) -> u32 {
         Direction::iter()
-            .map(|dir| self.rec(pos.move_in_dir(&dir), 1))
+            .map(|dir| self.rating_rec(pos.move_in_dir(&dir), 1))
             .sum()
     }
 
-    fn rec(&self, pos: Position, h: u8) -> u32 {
+    fn rating_rec(&self, pos: Position, h: u8) -> u32 {
         let height = match self.try_get(&pos) {
             Some(height) => height,
             None => return 0,
@@ -118,11 +24,11 @@ impl LavaMap {
         };
 
         Direction::iter()
-            .map(|dir| self.rec(pos.move_in_dir(&dir), height.0 + 1))
+            .map(|dir| self.rating_rec(pos.move_in_dir(&dir), height.0 + 1))
             .sum()
     }
 
-    fn total_trailhead_score(&self) -> u32 {
+    fn total_trailhead_rating(&self) -> u32 {
         let mut score = 0;
 
         for row in 0..self.height {
@@ -130,7 +36,7 @@ impl LavaMap {
                 let pos = Position::new(row as isize, col as isize);
                 if let Some(height) = self.try_get(&pos) {
                     if height.is_trailhead() {
-                        let s = self.score_trailhead(&pos);
+                        let s = self.rate_trailhead(&pos);
                         score += s;
                     }
                 }
@@ -141,8 +47,8 @@ impl LavaMap {
     }
 }
 
-pub fn solve() -> u32 {
-    LavaMap::from(include_str!("../input/part1.txt")).total_trailhead_score()
+pub fn solve(map: &LavaMap) -> u32 {
+    map.total_trailhead_rating()
 }
 
 #[cfg(test)]
@@ -161,7 +67,7 @@ mod tests {
 10456732"#;
 
         let map = LavaMap::from(input);
-        assert_eq!(map.score_trailhead(&Position::new(0, 2)), 20);
-        assert_eq!(map.total_trailhead_score(), 81);
+        assert_eq!(map.rate_trailhead(&Position::new(0, 2)), 20);
+        assert_eq!(map.total_trailhead_rating(), 81);
     }
 }
@@ -0,0 +1,86 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Eq, PartialEq, Debug)]
+pub enum Rule {
+    Replace,
+    Split,
+    Multiply,
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct Stone(u64);
+
+impl TryFrom<&str> for Stone {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(num) = value.parse::<u64>() {
+            Ok(Self(num))
+        } else {
+            Err("Invalid num")
+        }
+    }
+}
+
+impl Stone {
+    #[cfg(test)]
+    fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    fn rule(&self) -> Rule {
+        if self.0 == 0 {
+            Rule::Replace
+        } else if self.0.to_string().len().is_multiple_of(2) {
+            Rule::Split
+        } else {
+            Rule::Multiply
+        }
+    }
+
+    fn split(&self) -> (Self, Self) {
+        let str = self.0.to_string();
+        let parts = str.split_at(str.len() / 2);
+        (
+            Self::try_from(parts.0).unwrap(),
+            Self::try_from(parts.1).unwrap(),
+        )
+    }
+
+    fn multiply(&self) -> Self {
+        Self(self.0 * 2024)
+    }
+
+    fn replace(&self) -> Self {
+        Self(1)
+    }
+}
+
+pub fn parse_stones(input: &str) -> Vec<Stone> {
+    input
+        .split(" ")
+        .take_while(|num| !num.trim().is_empty())
+        .filter_map(|num| Stone::try_from(num).ok())
+        .collect()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Vec<Stone>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        parse_stones(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
@@ -1,65 +1,4 @@
-#[derive(Eq, PartialEq, Debug)]
-enum Rule {
-    Replace,
-    Split,
-    Multiply,
-}
-
-#[derive(Debug, Eq, PartialEq)]
-struct Stone(u64);
-
-impl TryFrom<&str> for Stone {
-    type Error = &'static str;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if let Some(num) = u64::from_str_radix(value, 10).ok() {
-            Ok(Self(num))
-        } else {
-            Err("Invalid num")
-        }
-    }
-}
-
-impl Stone {
-    fn new(value: u64) -> Self {
-        Self(value)
-    }
-
-    fn rule(&self) -> Rule {
-        if self.0 == 0 {
-            Rule::Replace
-        } else if self.0.to_string().len() % 2 == 0 {
-            Rule::Split
-        } else {
-            Rule::Multiply
-        }
-    }
-
-    fn split(&self) -> (Self, Self) {
-        let str = self.0.to_string();
-        let parts = str.split_at(str.len() / 2);
-        (
-            Self::try_from(parts.0).unwrap(),
-            Self::try_from(parts.1).unwrap(),
-        )
-    }
-
-    fn multiply(&self) -> Self {
-        Self(self.0 * 2024)
-    }
-
-    fn replace(&self) -> Self {
-        Self(1)
-    }
-}
-
-fn parse_stones(input: &str) -> Vec<Stone> {
-    input
-        .split(" ")
-        .take_while(|num| !num.trim().is_empty())
-        .filter_map(|num| Stone::try_from(num).ok())
-        .collect()
-}
+use crate::{Rule, Stone};
 
 fn blink_stones(stones: &mut Vec<Stone>) {
     let mut idx = 0;
@@ -78,8 +17,8 @@ fn blink_stones(stones: &mut Vec<Stone>) {
     }
 }
 
-fn count_blinking_stones(input: &str, n: u8) -> u64 {
-    let mut stones = parse_stones(input);
+fn count_blinking_stones(stones: &[Stone], n: u8) -> u64 {
+    let mut stones = stones.to_vec();
 
     for _ in 0..n {
         blink_stones(&mut stones);
@@ -88,15 +27,14 @@ fn count_blinking_stones(input: &str, n: u8) -> u64 {
     stones.len() as u64
 }
 
-pub fn solve() -> u64 {
-    count_blinking_stones(include_str!("../input/part1.txt"), 25)
+pub fn solve(stones: &[Stone]) -> u64 {
+    count_blinking_stones(stones, 25)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::part1::blink_stones;
-
     use super::*;
+    use crate::{parse_stones, Rule};
 
     #[test]
     fn stone_rule() {
@@ -131,6 +69,6 @@ mod tests {
 
     #[test]
     fn example() {
-        assert_eq!(count_blinking_stones("125 17", 25), 55312);
+        assert_eq!(count_blinking_stones(&parse_stones("125 17"), 25), 55312);
     }
 }
@@ -0,0 +1,34 @@
+use utils::{Answer, Grid, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Garden {
+    plots: Grid<char>,
+}
+
+impl From<&str> for Garden {
+    fn from(value: &str) -> Self {
+        Self {
+            plots: Grid::from(value.trim_end()),
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Garden;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Garden::from(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
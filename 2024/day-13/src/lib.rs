@@ -0,0 +1,103 @@
+use utils::{linalg, Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[inline]
+fn parse_num(part: &str) -> i128 {
+    let num_str = part
+        .chars()
+        .filter(|c| c.is_ascii_digit())
+        .collect::<String>();
+
+    num_str.parse::<i128>().unwrap()
+}
+
+#[derive(Clone, Copy)]
+pub struct Button {
+    x: i128,
+    y: i128,
+    token_cost: u8,
+}
+
+impl From<&str> for Button {
+    fn from(value: &str) -> Self {
+        let (first, second) = value.split_once(",").unwrap();
+        let token_cost = if first.contains("A") { 3 } else { 1 };
+        let x = parse_num(first);
+        let y = parse_num(second);
+        Self { x, y, token_cost }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Prize {
+    x: i128,
+    y: i128,
+}
+
+impl From<&str> for Prize {
+    fn from(value: &str) -> Self {
+        let (first, second) = value.split_once(",").unwrap();
+        let x = parse_num(first);
+        let y = parse_num(second);
+
+        Self { x, y }
+    }
+}
+
+pub struct Machine {
+    button_a: Button,
+    button_b: Button,
+    prize: Prize,
+}
+
+impl From<&str> for Machine {
+    fn from(value: &str) -> Self {
+        let mut lines = value.lines();
+
+        Self {
+            button_a: Button::from(lines.next().unwrap()),
+            button_b: Button::from(lines.next().unwrap()),
+            prize: Prize::from(lines.next().unwrap()),
+        }
+    }
+}
+
+impl Machine {
+    /// The fewest tokens that line the claw up with `prize`, `None` when no
+    /// whole number of presses can.
+    fn min_tokens(&self, prize: Prize) -> Option<u64> {
+        let (a, b) = (self.button_a, self.button_b);
+
+        linalg::min_cost_integer(
+            &[vec![a.x, b.x], vec![a.y, b.y]],
+            &[prize.x, prize.y],
+            &[a.token_cost as i128, b.token_cost as i128],
+        )
+        .expect("buttons that move the claw")
+        .map(|(_, tokens)| tokens as u64)
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<Machine>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input
+            .split("\n\n")
+            .take_while(|line| !line.is_empty())
+            .map(Machine::from)
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
@@ -0,0 +1,91 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct Position {
+    x: isize,
+    y: isize,
+}
+
+impl Position {
+    fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+}
+
+impl TryFrom<&str> for Position {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let nums = value
+            .strip_prefix("p=")
+            .ok_or("Invalid input".to_string())?
+            .split_once(",")
+            .ok_or("Invalid input".to_string())?;
+
+        Ok(Self {
+            x: nums.0.parse::<isize>().unwrap(),
+            y: nums.1.parse::<isize>().unwrap(),
+        })
+    }
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Velocity {
+    x: isize,
+    y: isize,
+}
+
+impl Velocity {
+    #[cfg(test)]
+    fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+}
+
+impl TryFrom<&str> for Velocity {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let nums = value
+            .strip_prefix("v=")
+            .ok_or("Invalid input".to_string())?
+            .split_once(",")
+            .ok_or("Invalid input".to_string())?;
+
+        Ok(Self {
+            x: nums.0.parse::<isize>().unwrap(),
+            y: nums.1.parse::<isize>().unwrap(),
+        })
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Vec<(Position, Velocity)>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(|line| {
+                let split = line.split_once(" ").unwrap();
+                (
+                    Position::try_from(split.0).unwrap(),
+                    Velocity::try_from(split.1).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
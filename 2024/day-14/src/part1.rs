@@ -1,62 +1,6 @@
 use std::collections::HashMap;
 
-#[derive(Eq, PartialEq, Debug)]
-struct Position {
-    x: isize,
-    y: isize,
-}
-
-impl Position {
-    fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
-}
-
-impl TryFrom<&str> for Position {
-    type Error = String;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let nums = value
-            .strip_prefix("p=")
-            .ok_or("Invalid input".to_string())?
-            .split_once(",")
-            .ok_or("Invalid input".to_string())?;
-
-        Ok(Self {
-            x: isize::from_str_radix(nums.0, 10).unwrap(),
-            y: isize::from_str_radix(nums.1, 10).unwrap(),
-        })
-    }
-}
-
-#[derive(Eq, PartialEq, Debug)]
-struct Velocity {
-    x: isize,
-    y: isize,
-}
-
-impl Velocity {
-    fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
-}
-
-impl TryFrom<&str> for Velocity {
-    type Error = String;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let nums = value
-            .strip_prefix("v=")
-            .ok_or("Invalid input".to_string())?
-            .split_once(",")
-            .ok_or("Invalid input".to_string())?;
-
-        Ok(Self {
-            x: isize::from_str_radix(nums.0, 10).unwrap(),
-            y: isize::from_str_radix(nums.1, 10).unwrap(),
-        })
-    }
-}
+use crate::{Position, Velocity};
 
 fn quad_for_pos(pos: &Position, width: isize, height: isize) -> Option<usize> {
     let mid_width = width / 2;
@@ -89,34 +33,29 @@ fn compute(pos: &Position, vel: &Velocity, width: isize, height: isize, num: isi
     Position::new(x, y)
 }
 
-fn it(input: &str, width: isize, height: isize, num: isize) -> u32 {
-    let map =
-        input
-            .lines()
-            .take_while(|line| !line.is_empty())
-            .fold(HashMap::new(), |mut map, line| {
-                let split = line.split_once(" ").unwrap();
-                let pos = Position::try_from(split.0).unwrap();
-                let vel = Velocity::try_from(split.1).unwrap();
-                let new_pos = compute(&pos, &vel, width, height, num);
-                if let Some(quad) = quad_for_pos(&new_pos, width, height) {
-                    let vec = map.entry(quad).or_insert(Vec::new());
-                    vec.push(new_pos);
-                };
-
-                map
-            });
-
-    map.iter().map(|(_, vec)| vec.len() as u32).product()
+fn it(robots: &[(Position, Velocity)], width: isize, height: isize, num: isize) -> u32 {
+    let map = robots.iter().fold(HashMap::new(), |mut map, (pos, vel)| {
+        let new_pos = compute(pos, vel, width, height, num);
+        if let Some(quad) = quad_for_pos(&new_pos, width, height) {
+            let vec = map.entry(quad).or_insert(Vec::new());
+            vec.push(new_pos);
+        };
+
+        map
+    });
+
+    map.values().map(|vec| vec.len() as u32).product()
 }
 
-pub fn solve() -> u32 {
-    it(include_str!("../input/part1.txt"), 101, 103, 100)
+pub fn solve(robots: &[(Position, Velocity)]) -> u32 {
+    it(robots, 101, 103, 100)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Day14;
+    use utils::Solution;
 
     #[test]
     fn parsing() {
@@ -126,7 +65,7 @@ mod tests {
 
     #[test]
     fn stuff() {
-        let test_cases = vec![
+        let test_cases = [
             (
                 Position::new(2, 4),
                 Velocity::new(2, -3),
@@ -163,7 +102,7 @@ mod tests {
 
         for (idx, (pos, vel, width, height, num, expected)) in test_cases.iter().enumerate() {
             assert_eq!(
-                compute(&pos, &vel, *width as isize, *height as isize, *num as isize),
+                compute(pos, vel, *width as isize, *height as isize, *num as isize),
                 *expected,
                 "Failed at test case {}",
                 idx + 1
@@ -186,7 +125,7 @@ p=7,3 v=-1,2
 p=2,4 v=2,-3
 p=9,5 v=-3,-3"#;
 
-        let val = it(input, 11, 7, 100);
+        let val = it(&Day14::parse(input), 11, 7, 100);
         assert_eq!(val, 12);
     }
 }
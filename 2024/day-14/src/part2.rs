@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+
+use crate::{Position, Velocity};
+
+fn tick(
+    positions: &[Position],
+    robots: &[(Position, Velocity)],
+    width: isize,
+    height: isize,
+) -> Vec<Position> {
+    positions
+        .iter()
+        .zip(robots)
+        .map(|(pos, (_, vel))| {
+            Position::new(
+                (pos.x + vel.x).rem_euclid(width),
+                (pos.y + vel.y).rem_euclid(height),
+            )
+        })
+        .collect()
+}
+
+/// The tree is drawn with every robot on a tile of its own, which does not
+/// happen in any other frame.
+fn is_tree(positions: &[Position]) -> bool {
+    let mut seen = HashSet::new();
+    positions.iter().all(|pos| seen.insert(pos))
+}
+
+fn first_tree(robots: &[(Position, Velocity)], width: isize, height: isize) -> Option<usize> {
+    let start = robots.iter().map(|(pos, _)| *pos).collect::<Vec<_>>();
+
+    // Each second can be undone by moving every robot backwards, so the
+    // frames come back round to the start before anything else repeats and
+    // there is no point looking past that
+    std::iter::successors(Some(start.clone()), |positions| {
+        Some(tick(positions, robots, width, height)).filter(|next| *next != start)
+    })
+    .position(|positions| is_tree(&positions))
+}
+
+pub fn solve(robots: &[(Position, Velocity)]) -> usize {
+    first_tree(robots, 101, 103).expect("the robots to draw a tree")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day14;
+    use utils::Solution;
+
+    #[test]
+    fn spreads_out() {
+        // Both robots start on the same tile and only ever share it every
+        // fifth second
+        let robots = Day14::parse("p=0,0 v=1,0\np=0,0 v=2,0");
+
+        assert_eq!(first_tree(&robots, 5, 1), Some(1));
+
+        let stuck = Day14::parse("p=0,0 v=5,0\np=0,0 v=0,0");
+        assert_eq!(first_tree(&stuck, 5, 1), None);
+    }
+}
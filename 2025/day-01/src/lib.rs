@@ -0,0 +1,59 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Eq, PartialEq, Debug)]
+pub enum Direction {
+    Right,
+    Left,
+}
+
+impl From<char> for Direction {
+    fn from(ch: char) -> Self {
+        match ch {
+            'L' => Self::Left,
+            'R' => Self::Right,
+            _ => panic!("invalid char"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Rotation {
+    direction: Direction,
+    distance: u32,
+}
+
+impl From<&str> for Rotation {
+    fn from(value: &str) -> Self {
+        let mut chars = value.chars();
+        let direction = Direction::from(chars.next().expect("a direction"));
+        let distance = chars.fold(0u32, |acc, c| {
+            c.to_digit(10).map(|d| acc * 10 + d).expect("a valid digit")
+        });
+
+        Self {
+            direction,
+            distance,
+        }
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Vec<Rotation>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.lines().map(Rotation::from).collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
@@ -1,39 +1,4 @@
-#[derive(Eq, PartialEq, Debug)]
-enum Direction {
-    Right,
-    Left,
-}
-
-impl From<char> for Direction {
-    fn from(ch: char) -> Self {
-        match ch {
-            'L' => Self::Left,
-            'R' => Self::Right,
-            _ => panic!("invalid char"),
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Rotation {
-    direction: Direction,
-    distance: u32,
-}
-
-impl From<&str> for Rotation {
-    fn from(value: &str) -> Self {
-        let mut chars = value.chars();
-        let direction = Direction::from(chars.next().expect("a direction"));
-        let distance = chars.fold(0u32, |acc, c| {
-            c.to_digit(10).map(|d| acc * 10 + d).expect("a valid digit")
-        });
-
-        Self {
-            direction,
-            distance,
-        }
-    }
-}
+use crate::{Direction, Rotation};
 
 fn rotate(distance: u32, rotation: &Rotation) -> (u32, u32) {
     let cycles = rotation.distance / 100;
@@ -56,13 +21,12 @@ fn rotate(distance: u32, rotation: &Rotation) -> (u32, u32) {
     }
 }
 
-pub fn solve() -> usize {
+pub fn solve(rotations: &[Rotation]) -> usize {
     let mut distance = 50;
     let mut times = 0usize;
 
-    for line in include_str!("../input/source.txt").lines() {
-        let rotation = Rotation::from(line);
-        let (new_distance, extra_times) = rotate(distance, &rotation);
+    for rotation in rotations {
+        let (new_distance, extra_times) = rotate(distance, rotation);
         distance = new_distance;
         times += extra_times as usize;
     }
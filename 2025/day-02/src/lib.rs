@@ -0,0 +1,44 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub type Id = usize;
+
+pub struct Range {
+    first: Id,
+    last: Id,
+}
+
+impl From<&str> for Range {
+    fn from(value: &str) -> Self {
+        let (first, last) = value.split_once('-').expect("valid parts");
+
+        Self {
+            first: first.trim().parse().expect("a valid id"),
+            last: last.trim().parse().expect("a valid id"),
+        }
+    }
+}
+
+fn num_digits(id: Id) -> usize {
+    if id == 0 { 1 } else { id.ilog10() as usize + 1 }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<Range>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.split(',').map(Range::from).collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
@@ -1,29 +1,9 @@
-type Id = usize;
-
-struct Range {
-    first: Id,
-    last: Id,
-}
-
-impl From<&str> for Range {
-    fn from(value: &str) -> Self {
-        let (first, last) = value.split_once('-').expect("valid parts");
-
-        Self {
-            first: first.trim().parse().expect("a valid id"),
-            last: last.trim().parse().expect("a valid id"),
-        }
-    }
-}
+use crate::{Id, Range, num_digits};
 
 fn nth_digit_from_right(n: Id, index: usize) -> usize {
     (n / 10_usize.pow(index as u32)) % 10
 }
 
-fn num_digits(id: Id) -> usize {
-    if id == 0 { 1 } else { id.ilog10() as usize + 1 }
-}
-
 fn is_odd(v: usize) -> bool {
     (v & 1) > 0
 }
@@ -60,11 +40,8 @@ impl Range {
     }
 }
 
-pub fn solve() -> usize {
-    include_str!("../input/input.txt")
-        .split(',')
-        .map(|l| Range::from(l).sum_invalid_ids())
-        .sum()
+pub fn solve(ranges: &[Range]) -> usize {
+    ranges.iter().map(|range| range.sum_invalid_ids()).sum()
 }
 
 #[cfg(test)]
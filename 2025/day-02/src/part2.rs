@@ -1,24 +1,4 @@
-type Id = usize;
-
-struct Range {
-    first: Id,
-    last: Id,
-}
-
-impl From<&str> for Range {
-    fn from(value: &str) -> Self {
-        let (first, last) = value.split_once('-').expect("valid parts");
-
-        Self {
-            first: first.trim().parse().expect("a valid id"),
-            last: last.trim().parse().expect("a valid id"),
-        }
-    }
-}
-
-fn num_digits(id: Id) -> usize {
-    if id == 0 { 1 } else { id.ilog10() as usize + 1 }
-}
+use crate::{Id, Range, num_digits};
 
 fn slice_digits(n: Id, start: usize, end: usize) -> usize {
     let len = end - start + 1;
@@ -33,7 +13,7 @@ fn is_invalid_id(id: Id) -> bool {
     // Patterns greater than half the number of digits cannot repeat
     for i in 1..=(digits / 2) {
         // If we cannot get an equal amount of slices, no need to check
-        if digits % i != 0 {
+        if !digits.is_multiple_of(i) {
             continue;
         }
 
@@ -49,7 +29,7 @@ fn is_invalid_id(id: Id) -> bool {
 }
 
 impl Range {
-    fn sum_invalid_ids(&self) -> usize {
+    fn sum_repeated_pattern_ids(&self) -> usize {
         let mut sum = 0;
 
         for id in self.first..=self.last {
@@ -62,10 +42,10 @@ impl Range {
     }
 }
 
-pub fn solve() -> usize {
-    include_str!("../input/input.txt")
-        .split(',')
-        .map(|l| Range::from(l).sum_invalid_ids())
+pub fn solve(ranges: &[Range]) -> usize {
+    ranges
+        .iter()
+        .map(|range| range.sum_repeated_pattern_ids())
         .sum()
 }
 
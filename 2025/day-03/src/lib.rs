@@ -0,0 +1,37 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct BatteryBank {
+    batteries: Vec<u8>,
+}
+
+impl From<&str> for BatteryBank {
+    fn from(value: &str) -> Self {
+        let batteries = value
+            .chars()
+            .map(|c| c.to_digit(10).expect("a digit") as u8)
+            .collect::<Vec<_>>();
+
+        Self { batteries }
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Vec<BatteryBank>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.lines().map(BatteryBank::from).collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
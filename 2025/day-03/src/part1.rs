@@ -1,17 +1,4 @@
-struct BatteryBank {
-    batteries: Vec<u8>,
-}
-
-impl From<&str> for BatteryBank {
-    fn from(value: &str) -> Self {
-        let batteries = value
-            .chars()
-            .map(|c| c.to_digit(10).expect("a digit") as u8)
-            .collect::<Vec<_>>();
-
-        Self { batteries }
-    }
-}
+use crate::BatteryBank;
 
 impl BatteryBank {
     fn joltage(&self) -> u8 {
@@ -40,9 +27,6 @@ impl BatteryBank {
     }
 }
 
-pub fn solve() -> usize {
-    include_str!("../input/input.txt")
-        .lines()
-        .map(|line| BatteryBank::from(line).joltage() as usize)
-        .sum()
+pub fn solve(banks: &[BatteryBank]) -> usize {
+    banks.iter().map(|bank| bank.joltage() as usize).sum()
 }
@@ -1,22 +1,9 @@
-const BATTERIES_TO_COUNT: usize = 12;
-
-struct BatteryBank {
-    batteries: Vec<u8>,
-}
-
-impl From<&str> for BatteryBank {
-    fn from(value: &str) -> Self {
-        let batteries = value
-            .chars()
-            .map(|c| c.to_digit(10).expect("a digit") as u8)
-            .collect::<Vec<_>>();
+use crate::BatteryBank;
 
-        Self { batteries }
-    }
-}
+const BATTERIES_TO_COUNT: usize = 12;
 
 impl BatteryBank {
-    fn joltage(&self) -> usize {
+    fn max_joltage(&self) -> usize {
         let mut num_joltages_to_remove = self.batteries.len() - BATTERIES_TO_COUNT;
         let mut stack = Vec::with_capacity(BATTERIES_TO_COUNT);
 
@@ -40,9 +27,6 @@ impl BatteryBank {
     }
 }
 
-pub fn solve() -> usize {
-    include_str!("../input/input.txt")
-        .lines()
-        .map(|line| BatteryBank::from(line).joltage())
-        .sum()
+pub fn solve(banks: &[BatteryBank]) -> usize {
+    banks.iter().map(|bank| bank.max_joltage()).sum()
 }
@@ -0,0 +1,38 @@
+use utils::{Answer, Grid, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Clone, Eq, PartialEq)]
+pub enum Spot {
+    Paper,
+    Empty,
+}
+
+impl From<char> for Spot {
+    fn from(value: char) -> Self {
+        match value {
+            '@' => Self::Paper,
+            '.' => Self::Empty,
+            _ => panic!("invalid input"),
+        }
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Grid<Spot>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Grid::from(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
@@ -0,0 +1,56 @@
+use utils::{Answer, IntervalSet, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Inventory {
+    fresh_ids: IntervalSet<usize>,
+    ingredients: Vec<usize>,
+}
+
+impl From<&str> for Inventory {
+    fn from(value: &str) -> Self {
+        let mut lines = value.lines();
+        let mut fresh_ids = IntervalSet::new();
+        let mut ingredients = Vec::new();
+
+        for line in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+
+            let (start, end) = line.split_once('-').expect("a valid range");
+            fresh_ids.insert(
+                start.parse::<usize>().expect("a valid num")
+                    ..=end.parse::<usize>().expect("a valid num"),
+            );
+        }
+
+        for line in lines.by_ref() {
+            ingredients.push(line.parse::<usize>().expect("a valid num"));
+        }
+
+        Self {
+            fresh_ids,
+            ingredients,
+        }
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = Inventory;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Inventory::from(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
@@ -1,53 +1,25 @@
-struct Inventory {
-    fresh_id_ranges: Vec<(usize, usize)>,
-    ingredients: Vec<usize>,
-}
-
-impl From<&str> for Inventory {
-    fn from(value: &str) -> Self {
-        let mut lines = value.lines();
-        let mut fresh_id_ranges = Vec::new();
-        let mut ingredients = Vec::new();
-
-        for line in lines.by_ref() {
-            if line.is_empty() {
-                break;
-            }
-
-            let (start, end) = line.split_once('-').expect("a valid range");
-            fresh_id_ranges.push((
-                start.parse::<usize>().expect("a valid num"),
-                end.parse::<usize>().expect("a valid num"),
-            ));
-        }
-
-        for line in lines.by_ref() {
-            ingredients.push(line.parse::<usize>().expect("a valid num"));
-        }
-
-        Self {
-            fresh_id_ranges,
-            ingredients,
-        }
-    }
-}
+use crate::Inventory;
 
 impl Inventory {
     fn count_fresh_ingredient(&self) -> usize {
-        let mut sum = 0;
-        for ingredient in &self.ingredients {
-            for range in &self.fresh_id_ranges {
-                if ingredient <= &range.1 && ingredient >= &range.0 {
-                    sum += 1;
-                    break;
-                }
-            }
-        }
-
-        sum
+        self.ingredients
+            .iter()
+            .filter(|&&ingredient| self.fresh_ids.contains(ingredient))
+            .count()
     }
 }
 
-pub fn solve() -> usize {
-    Inventory::from(include_str!("../input/input.txt")).count_fresh_ingredient()
+pub fn solve(inventory: &Inventory) -> usize {
+    inventory.count_fresh_ingredient()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32";
+        assert_eq!(solve(&Inventory::from(input)), 3);
+    }
 }
@@ -1,59 +1,27 @@
-type IdRange = (usize, usize);
+use crate::Inventory;
 
-fn parse_id_range(value: &str) -> IdRange {
-    let (start, end) = value.split_once('-').expect("a delimiter");
-
-    (
-        start.parse().expect("a valid num"),
-        end.parse().expect("a valid num"),
-    )
+impl Inventory {
+    fn count_fresh_ids(&self) -> usize {
+        usize::try_from(self.fresh_ids.total_len()).expect("fewer fresh ids than fit in a usize")
+    }
 }
 
-struct Inventory {
-    fresh_id_ranges: Vec<IdRange>,
+pub fn solve(inventory: &Inventory) -> usize {
+    inventory.count_fresh_ids()
 }
 
-impl From<&str> for Inventory {
-    fn from(value: &str) -> Self {
-        let mut ranges = value
-            .lines()
-            .take_while(|l| !l.is_empty())
-            .map(parse_id_range)
-            .collect::<Vec<_>>();
-        ranges.sort();
-
-        assert!(!ranges.is_empty());
-
-        let mut out = Vec::with_capacity(ranges.len() / 2);
-        out.push(ranges[0]);
-        let mut last_idx = 0;
-
-        // Can just calc all in one loop don't need to go over them again later
-        // in our method
-        for range in ranges.iter().skip(1) {
-            let prev = out[last_idx];
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-            // (10-13), (14-16) can become (10-16)
-            if prev.1 >= range.0 - 1 {
-                out[last_idx] = (prev.0, range.1.max(prev.1));
-            } else {
-                out.push(*range);
-                last_idx += 1;
-            }
-        }
-
-        Self {
-            fresh_id_ranges: out,
-        }
+    #[test]
+    fn example() {
+        let input = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32";
+        assert_eq!(solve(&Inventory::from(input)), 14);
     }
-}
 
-impl Inventory {
-    fn count_fresh_ingredients(&self) -> usize {
-        self.fresh_id_ranges.iter().map(|r| r.1 - r.0 + 1).sum()
+    #[test]
+    fn starts_at_zero() {
+        assert_eq!(solve(&Inventory::from("1-2\n0-0\n\n0")), 3);
     }
 }
-
-pub fn solve() -> usize {
-    Inventory::from(include_str!("../input/input.txt")).count_fresh_ingredients()
-}
@@ -0,0 +1,109 @@
+use utils::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+const NUM_SIZE: usize = 4;
+
+#[derive(Debug)]
+pub enum Operation {
+    Add,
+    Multiply,
+}
+
+impl TryFrom<char> for Operation {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '+' => Ok(Self::Add),
+            '*' => Ok(Self::Multiply),
+            _ => Err("invalid operation"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Num([usize; NUM_SIZE]);
+
+#[derive(Debug)]
+pub struct Homework {
+    numbers: Vec<Vec<Num>>,
+    operations: Vec<Operation>,
+}
+
+impl From<&str> for Homework {
+    fn from(value: &str) -> Self {
+        let mut numbers = Vec::new();
+        let mut operations = Vec::new();
+        let mut operation_idxs = Vec::new();
+        let chars = value
+            .lines()
+            .map(|l| l.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        for (idx, c) in chars[chars.len() - 1].iter().enumerate() {
+            if let Ok(op) = Operation::try_from(*c) {
+                operations.push(op);
+                operation_idxs.push(idx);
+            }
+        }
+
+        for (i, op_idx) in operation_idxs.iter().enumerate() {
+            let go_until_idx = if i >= operation_idxs.len() - 1 {
+                chars[0].len()
+            } else {
+                operation_idxs[i + 1] - 1
+            };
+
+            let mut nums = Vec::new();
+            // Ignore operations in last row
+            for row in &chars[..chars.len() - 1] {
+                let mut num = [0usize; NUM_SIZE];
+                for (num_idx, idx) in (*op_idx..go_until_idx).enumerate() {
+                    match row[idx].to_digit(10) {
+                        Some(d) => num[num_idx] = d as usize,
+                        None => num[num_idx] = 0,
+                    }
+                }
+                nums.push(Num(num));
+            }
+
+            numbers.push(nums);
+        }
+
+        assert!(numbers.len() == operations.len());
+
+        Self {
+            numbers,
+            operations,
+        }
+    }
+}
+
+/// Blank spaces are stored as `0`, so they are skipped when building a number
+fn append_digit(acc: usize, digit: usize) -> usize {
+    if digit > 0 {
+        acc * 10 + digit
+    } else {
+        acc
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = Homework;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Homework::from(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
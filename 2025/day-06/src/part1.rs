@@ -1,58 +1,10 @@
-#[derive(Debug)]
-enum Operation {
-    Add,
-    Multiply,
-}
-
-impl TryFrom<&str> for Operation {
-    type Error = &'static str;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value.trim() {
-            "+" => Ok(Self::Add),
-            "*" => Ok(Self::Multiply),
-            _ => Err("invalid operation"),
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Homework {
-    numbers: Vec<Vec<usize>>,
-    operations: Vec<Operation>,
-}
-
-impl From<&str> for Homework {
-    fn from(value: &str) -> Self {
-        let mut numbers = Vec::new();
-        let mut operations = Vec::new();
-
-        for line in value.lines() {
-            let mut parts = line.split_whitespace();
-            let first_part = parts.next().expect("at least one part");
-            let mut nums = Vec::new();
-
-            if let Ok(op) = Operation::try_from(first_part) {
-                operations.push(op);
-                for part in parts {
-                    operations.push(Operation::try_from(part).expect("a valid operation"));
-                }
-            } else {
-                nums.push(first_part.parse().expect("a valid number"));
-                for part in parts {
-                    nums.push(part.parse().expect("a valid num"));
-                }
-
-                numbers.push(nums);
-            }
-        }
-
-        assert!(numbers[0].len() == operations.len());
+use crate::{append_digit, Homework, Num, Operation};
 
-        Self {
-            numbers,
-            operations,
-        }
+impl Num {
+    fn row_value(&self) -> usize {
+        self.0
+            .iter()
+            .fold(0, |acc, digit| append_digit(acc, *digit))
     }
 }
 
@@ -61,25 +13,11 @@ impl Homework {
         let mut total = 0;
 
         for i in 0..self.operations.len() {
-            total += match self.operations[i] {
-                Operation::Add => {
-                    let mut tot = 0;
-
-                    for num_idx in 0..self.numbers.len() {
-                        tot += self.numbers[num_idx][i];
-                    }
-
-                    tot
-                }
-                Operation::Multiply => {
-                    let mut tot = 1;
-
-                    for num_idx in 0..self.numbers.len() {
-                        tot *= self.numbers[num_idx][i];
-                    }
+            let nums = self.numbers[i].iter().map(Num::row_value);
 
-                    tot
-                }
+            total += match self.operations[i] {
+                Operation::Add => nums.sum::<usize>(),
+                Operation::Multiply => nums.product(),
             }
         }
 
@@ -87,6 +25,20 @@ impl Homework {
     }
 }
 
-pub fn solve() -> usize {
-    Homework::from(include_str!("../input/input.txt")).total()
+pub fn solve(homework: &Homework) -> usize {
+    homework.total()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day06;
+    use utils::Solution;
+
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
+        assert_eq!(solve(&Day06::parse(input)), 4277556);
+    }
 }
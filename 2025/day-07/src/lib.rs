@@ -0,0 +1,59 @@
+use std::fmt::Debug;
+
+use utils::{Answer, Grid, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+pub enum Object {
+    Splitter,
+    Empty,
+    Start,
+    Beam(usize),
+}
+
+impl Debug for Object {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Object::Empty => ".".to_string(),
+                Object::Start => "S".to_string(),
+                Object::Splitter => "^".to_string(),
+                Object::Beam(c) => c.to_string(),
+            }
+        )
+    }
+}
+
+impl From<char> for Object {
+    fn from(value: char) -> Self {
+        match value {
+            '^' => Self::Splitter,
+            '.' => Self::Empty,
+            'S' => Self::Start,
+            '|' => Self::Beam(1),
+            _ => panic!("Invalid object"),
+        }
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = Grid<Object>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Grid::from(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::solve(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::solve(parsed).into()
+    }
+}
@@ -1,34 +1,16 @@
 use utils::{Direction, Grid, Position};
 
-#[derive(Eq, PartialEq, Debug, Copy, Clone)]
-enum Object {
-    Splitter,
-    Empty,
-    Start,
-    Beam,
-}
-
-impl From<char> for Object {
-    fn from(value: char) -> Self {
-        match value {
-            '^' => Self::Splitter,
-            '.' => Self::Empty,
-            'S' => Self::Start,
-            '|' => Self::Beam,
-            _ => panic!("Invalid object"),
-        }
-    }
-}
+use crate::Object;
 
-pub fn solve() -> usize {
-    let mut grid: Grid<Object> = Grid::from(include_str!("../input/input.txt"));
+pub fn solve(grid: &Grid<Object>) -> usize {
+    let mut grid = grid.clone();
     let pos = grid
         .iter_with_coords()
         .find(|(_, i)| **i == Object::Start)
         .map(|(c, _)| c)
         .and_then(|c| c.new_in_dir(Direction::Down));
 
-    grid.set(pos.as_ref().expect("a starting pos"), Object::Beam);
+    grid.set(pos.as_ref().expect("a starting pos"), Object::Beam(1));
     let mut num_splits = 0;
 
     for y in 2..grid.height() {
@@ -40,15 +22,15 @@ pub fn solve() -> usize {
                 .get(&pos.new_in_dir(Direction::Up).expect("a valid pos"))
                 .expect("an obj");
 
-            if matches!(above, Object::Beam) {
+            if matches!(above, Object::Beam(_)) {
                 if matches!(cur, Object::Splitter) {
                     pos.new_in_dir(Direction::Left)
-                        .and_then(|pos| grid.set(&pos, Object::Beam));
+                        .and_then(|pos| grid.set(&pos, Object::Beam(1)));
                     pos.new_in_dir(Direction::Right)
-                        .and_then(|pos| grid.set(&pos, Object::Beam));
+                        .and_then(|pos| grid.set(&pos, Object::Beam(1)));
                     num_splits += 1;
                 } else {
-                    grid.set(&pos, Object::Beam);
+                    grid.set(&pos, Object::Beam(1));
                 }
             }
         }
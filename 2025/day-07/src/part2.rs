@@ -1,44 +1,9 @@
-use std::fmt::Debug;
-
 use utils::{Direction, Grid, Position};
 
-#[derive(Eq, PartialEq, Copy, Clone)]
-enum Object {
-    Splitter,
-    Empty,
-    Start,
-    Beam(usize),
-}
-
-impl Debug for Object {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Object::Empty => ".".to_string(),
-                Object::Start => "S".to_string(),
-                Object::Splitter => "^".to_string(),
-                Object::Beam(c) => c.to_string(),
-            }
-        )
-    }
-}
-
-impl From<char> for Object {
-    fn from(value: char) -> Self {
-        match value {
-            '^' => Self::Splitter,
-            '.' => Self::Empty,
-            'S' => Self::Start,
-            '|' => Self::Beam(1),
-            _ => panic!("Invalid object"),
-        }
-    }
-}
+use crate::Object;
 
-pub fn solve() -> usize {
-    let mut grid: Grid<Object> = Grid::from(include_str!("../input/input.txt"));
+pub fn solve(grid: &Grid<Object>) -> usize {
+    let mut grid = grid.clone();
     let pos = grid
         .iter_with_coords()
         .find(|(_, i)| **i == Object::Start)
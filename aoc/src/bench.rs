@@ -0,0 +1,269 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use serde::{Deserialize, Serialize};
+use utils::{InputError, InputSource, Part};
+
+use crate::registry::Day;
+
+/// What gets timed for each day.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part1 => write!(f, "part 1"),
+            Self::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// Summary of the samples taken for one stage, in nanoseconds.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub year: u16,
+    pub day: u8,
+    pub stage: Stage,
+    pub min: u64,
+    pub median: u64,
+    pub p95: u64,
+}
+
+impl Timing {
+    fn new(year: u16, day: u8, stage: Stage, mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample");
+        samples.sort();
+        let nanos = |duration: Duration| duration.as_nanos() as u64;
+        let percentile = |p: usize| samples[(samples.len() - 1) * p / 100];
+
+        Self {
+            year,
+            day,
+            stage,
+            min: nanos(samples[0]),
+            median: nanos(percentile(50)),
+            p95: nanos(percentile(95)),
+        }
+    }
+}
+
+/// One `aoc bench` invocation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Run {
+    pub commit: String,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub timings: Vec<Timing>,
+}
+
+pub struct Config {
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+fn sample(config: &Config, mut f: impl FnMut()) -> Vec<Duration> {
+    for _ in 0..config.warmup {
+        f();
+    }
+
+    (0..config.samples.max(1))
+        .map(|_| {
+            let now = Instant::now();
+            f();
+            now.elapsed()
+        })
+        .collect()
+}
+
+/// Times parsing and both parts of a day against its real input, `None`
+/// when the input is not available.
+pub fn bench_day(entry: &Day, config: &Config) -> Result<Option<Vec<Timing>>, String> {
+    let input = match utils::read_input(entry.year, entry.day, &InputSource::Default) {
+        Ok(input) => input,
+        Err(InputError::Missing(_)) => return Ok(None),
+        Err(err) => return Err(err.to_string()),
+    };
+
+    let solution = entry.solution;
+    let parsed = solution.parse(&input);
+    let mut timings = vec![Timing::new(
+        entry.year,
+        entry.day,
+        Stage::Parse,
+        sample(config, || {
+            std::hint::black_box(solution.parse(&input));
+        }),
+    )];
+
+    for (part, stage) in [(Part::One, Stage::Part1), (Part::Two, Stage::Part2)] {
+        let samples = sample(config, || {
+            std::hint::black_box(solution.solve(parsed.as_ref(), part));
+        });
+        timings.push(Timing::new(entry.year, entry.day, stage, samples));
+    }
+
+    Ok(Some(timings))
+}
+
+/// The history file, `.aoc/bench.json` at the workspace root.
+pub fn history_path() -> PathBuf {
+    crate::state_dir().join("bench.json")
+}
+
+pub fn load_history(path: &Path) -> Result<Vec<Run>, String> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents)
+            .map_err(|err| format!("invalid history in {}: {err}", path.display())),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(format!("could not read {}: {err}", path.display())),
+    }
+}
+
+pub fn save_history(path: &Path, history: &[Run]) -> Result<(), String> {
+    let write = || {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(history).expect("history to serialize");
+        std::fs::write(path, json)
+    };
+
+    write().map_err(|err| format!("could not write {}: {err}", path.display()))
+}
+
+/// The short hash of the checked out commit, marked when the tree has
+/// uncommitted changes.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) if git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty()) => {
+            format!("{commit}-dirty")
+        }
+        Some(commit) => commit,
+        None => "unknown".to_string(),
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// The most recent run from a different commit that timed the same stage.
+pub fn previous<'a>(history: &'a [Run], run: &Run, timing: &Timing) -> Option<&'a Timing> {
+    history
+        .iter()
+        .rev()
+        .filter(|old| old.commit != run.commit)
+        .flat_map(|old| &old.timings)
+        .find(|old| old.year == timing.year && old.day == timing.day && old.stage == timing.stage)
+}
+
+/// Sum of the median times of every stage, per year.
+pub fn year_totals(timings: &[Timing]) -> BTreeMap<u16, Duration> {
+    let mut totals = BTreeMap::new();
+    for timing in timings {
+        *totals.entry(timing.year).or_default() += Duration::from_nanos(timing.median);
+    }
+    totals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(stage: Stage, median: u64) -> Timing {
+        Timing {
+            year: 2024,
+            day: 1,
+            stage,
+            min: median,
+            median,
+            p95: median,
+        }
+    }
+
+    #[test]
+    fn summary() {
+        let samples = (1..=100).rev().map(Duration::from_nanos).collect();
+        let timing = Timing::new(2024, 1, Stage::Part1, samples);
+
+        assert_eq!(timing.min, 1);
+        assert_eq!(timing.median, 50);
+        assert_eq!(timing.p95, 95);
+
+        let single = Timing::new(2024, 1, Stage::Part1, vec![Duration::from_nanos(7)]);
+        assert_eq!((single.min, single.median, single.p95), (7, 7, 7));
+    }
+
+    #[test]
+    fn samples_after_warmup() {
+        let mut calls = 0;
+        let config = Config {
+            warmup: 3,
+            samples: 5,
+        };
+
+        assert_eq!(sample(&config, || calls += 1).len(), 5);
+        assert_eq!(calls, 8);
+    }
+
+    #[test]
+    fn history_round_trip() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-bench-{}", std::process::id()))
+            .join("bench.json");
+        let history = vec![Run {
+            commit: "abc1234".to_string(),
+            timestamp: 1,
+            timings: vec![timing(Stage::Parse, 10), timing(Stage::Part2, 20)],
+        }];
+
+        save_history(&path, &history).unwrap();
+        assert_eq!(load_history(&path).unwrap(), history);
+    }
+
+    #[test]
+    fn compares_with_other_commits() {
+        let run = |commit: &str, median| Run {
+            commit: commit.to_string(),
+            timestamp: 0,
+            timings: vec![timing(Stage::Part1, median)],
+        };
+        let history = vec![run("aaa", 10), run("bbb", 20), run("ccc", 30)];
+        let current = run("ccc", 40);
+
+        let old = previous(&history, &current, &current.timings[0]).unwrap();
+        assert_eq!(old.median, 20);
+    }
+
+    #[test]
+    fn totals() {
+        let mut timings = vec![timing(Stage::Parse, 10), timing(Stage::Part1, 20)];
+        timings.push(Timing {
+            year: 2025,
+            ..timing(Stage::Part2, 5)
+        });
+
+        let totals = year_totals(&timings);
+        assert_eq!(totals[&2024], Duration::from_nanos(30));
+        assert_eq!(totals[&2025], Duration::from_nanos(5));
+    }
+}
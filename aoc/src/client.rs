@@ -0,0 +1,168 @@
+use std::path::PathBuf;
+
+use utils::Part;
+
+use crate::submit::Verdict;
+
+/// Session token used to authenticate with adventofcode.com.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Overrides the site the client talks to, mostly useful for tests.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/Squshy/advent-of-code";
+
+/// The HTTP requests the runner makes. Kept behind a trait so tests can talk
+/// to a stub server or a fake instead of the real site.
+pub trait HttpClient {
+    fn get(&self, url: &str, session: &str) -> Result<String, String>;
+
+    /// Posts a url encoded form.
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, String>;
+}
+
+/// [`HttpClient`] backed by `ureq`.
+pub struct UreqClient;
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, session: &str) -> Result<String, String> {
+        ureq::get(url)
+            .header("Cookie", &format!("session={session}"))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|err| format!("request to {url} failed: {err}"))
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        ureq::post(url)
+            .header("Cookie", &format!("session={session}"))
+            .header("User-Agent", USER_AGENT)
+            .send_form(form.iter().copied())
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|err| format!("request to {url} failed: {err}"))
+    }
+}
+
+/// Talks to adventofcode.com, or whichever site `base_url` points at.
+pub struct Client<H> {
+    http: H,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl<H: HttpClient> Client<H> {
+    pub fn new(http: H, base_url: impl Into<String>, session: Option<String>) -> Self {
+        Self {
+            http,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    fn session(&self) -> Result<&str, String> {
+        self.session.as_deref().ok_or(format!(
+            "no session token, set {SESSION_VAR} or write it to ~/.config/aoc/session"
+        ))
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        self.http.get(&url, self.session()?)
+    }
+
+    pub fn answer(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<Verdict, String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+        let page = self.http.post(
+            &url,
+            self.session()?,
+            &[("level", &level), ("answer", answer)],
+        )?;
+
+        Verdict::from_page(&page).ok_or(format!("could not understand the response from {url}"))
+    }
+}
+
+impl Client<UreqClient> {
+    /// Client configured from the environment and the config file.
+    pub fn from_env() -> Self {
+        let base_url = std::env::var(BASE_URL_VAR).unwrap_or(DEFAULT_BASE_URL.to_string());
+        Self::new(UreqClient, base_url, session_token())
+    }
+}
+
+fn config_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("aoc"))
+}
+
+fn session_file() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("session"))
+}
+
+/// Reads the session token from `AOC_SESSION`, falling back to
+/// `~/.config/aoc/session`.
+pub fn session_token() -> Option<String> {
+    let token = match std::env::var(SESSION_VAR) {
+        Ok(token) => token,
+        Err(_) => std::fs::read_to_string(session_file()?).ok()?,
+    };
+    let token = token.trim();
+
+    (!token.is_empty()).then(|| token.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::StubServer;
+
+    #[test]
+    fn sends_session_cookie() {
+        let server = StubServer::start(200, "1\n2\n3\n");
+        let client = Client::new(UreqClient, &server.url, Some("abc".to_string()));
+
+        assert_eq!(client.input(2025, 7).unwrap(), "1\n2\n3\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2025/day/7/input "));
+        assert!(requests[0].to_lowercase().contains("cookie: session=abc"));
+    }
+
+    #[test]
+    fn error_status() {
+        let server = StubServer::start(400, "Please log in");
+        let client = Client::new(UreqClient, &server.url, Some("abc".to_string()));
+
+        assert!(client.input(2025, 7).is_err());
+    }
+
+    #[test]
+    fn posts_answer() {
+        let server = StubServer::start(200, "<article><p>That's the right answer!</p></article>");
+        let client = Client::new(UreqClient, &server.url, Some("abc".to_string()));
+
+        assert_eq!(
+            client.answer(2025, 7, Part::Two, "1234"),
+            Ok(Verdict::Correct)
+        );
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2025/day/7/answer "));
+        assert!(requests[0].ends_with("level=2&answer=1234"));
+    }
+
+    #[test]
+    fn missing_session() {
+        let server = StubServer::start(200, "");
+        let client = Client::new(UreqClient, &server.url, None);
+
+        let err = client.input(2025, 7).unwrap_err();
+        assert!(err.starts_with("no session token"));
+        assert!(server.requests().is_empty());
+    }
+}
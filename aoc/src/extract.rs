@@ -0,0 +1,239 @@
+use std::path::{Path, PathBuf};
+
+use utils::EXPECTED_FILE;
+
+/// The examples and highlighted answer from one part of a puzzle page.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Section {
+    pub examples: Vec<String>,
+    pub answer: Option<String>,
+}
+
+/// Replaces the HTML entities the puzzle pages use.
+fn decode(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let ch = entity.and_then(|(name, _)| match name {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = match name.strip_prefix("#x").or(name.strip_prefix("#X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => name.strip_prefix('#')?.parse().ok()?,
+                };
+                char::from_u32(code)
+            }
+        });
+
+        match (ch, entity) {
+            (Some(ch), Some((_, end))) => {
+                decoded.push(ch);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Drops every tag, keeping the text between them.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+
+    decode(&text)
+}
+
+/// Every piece of `html` between `open` and `close`.
+fn between<'a>(html: &'a str, open: &'a str, close: &'a str) -> impl Iterator<Item = &'a str> {
+    let mut rest = html;
+    std::iter::from_fn(move || {
+        let start = rest.find(open)? + open.len();
+        let end = start + rest[start..].find(close)?;
+        let found = &rest[start..end];
+        rest = &rest[end + close.len()..];
+        Some(found)
+    })
+}
+
+/// Splits a saved puzzle page into one section per part. Examples are the
+/// `<pre><code>` blocks and the answer is the last `<code><em>` highlight,
+/// which is where the puzzle states the example's result.
+pub fn sections(page: &str) -> Vec<Section> {
+    let articles = between(page, "<article", "</article>").collect::<Vec<_>>();
+    let articles = if articles.is_empty() {
+        vec![page]
+    } else {
+        articles
+    };
+
+    articles
+        .into_iter()
+        .map(|article| Section {
+            examples: between(article, "<pre><code>", "</code></pre>")
+                .map(strip_tags)
+                .collect(),
+            answer: between(article, "<code><em>", "</em></code>")
+                .last()
+                .map(strip_tags),
+        })
+        .collect()
+}
+
+/// Writes each example as `example.txt`, `example-2.txt`, ... into `dir`,
+/// along with an expected answers file pairing each part with the last
+/// example seen by the time its answer is given. Returns the written files.
+pub fn write_fixtures(dir: &Path, sections: &[Section]) -> Result<Vec<PathBuf>, String> {
+    let write = |path: PathBuf, contents: &str| {
+        std::fs::create_dir_all(dir)
+            .and_then(|_| std::fs::write(&path, contents))
+            .map(|_| path.clone())
+            .map_err(|err| format!("could not write {}: {err}", path.display()))
+    };
+
+    let mut written = Vec::new();
+    let mut expected = String::new();
+    let mut latest = None;
+    for (part, section) in sections.iter().take(2).enumerate() {
+        for example in &section.examples {
+            let name = match written.len() {
+                0 => "example.txt".to_string(),
+                n => format!("example-{}.txt", n + 1),
+            };
+            written.push(write(dir.join(&name), example)?);
+            latest = Some(name);
+        }
+
+        if let (Some(answer), Some(file)) = (&section.answer, &latest) {
+            expected.push_str(&format!("{} {file} {answer}\n", part + 1));
+        }
+    }
+
+    if written.is_empty() {
+        return Err("no examples found on the page".to_string());
+    }
+    if !expected.is_empty() {
+        written.push(write(dir.join(EXPECTED_FILE), &expected)?);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>In this example, the calibration values are <code>12</code> and <code>38</code>. Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54916</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>two1nine
+<em>eight</em>wothree &amp; x &lt; y
+</code></pre>
+<p>Adding these together produces <code><em>281</em></code>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode("a &lt;b&gt; &amp; &quot;c&quot; &#39;d&#x27; &bogus; &"),
+            "a <b> & \"c\" 'd' &bogus; &"
+        );
+    }
+
+    #[test]
+    fn finds_sections() {
+        assert_eq!(
+            sections(PAGE),
+            vec![
+                Section {
+                    examples: vec!["1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n".to_string()],
+                    answer: Some("142".to_string()),
+                },
+                Section {
+                    examples: vec!["two1nine\neightwothree & x < y\n".to_string()],
+                    answer: Some("281".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_fixtures() {
+        let dir = std::env::temp_dir().join(format!("aoc-extract-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let written = write_fixtures(&dir, &sections(PAGE)).unwrap();
+        assert_eq!(
+            written,
+            vec![
+                dir.join("example.txt"),
+                dir.join("example-2.txt"),
+                dir.join(EXPECTED_FILE)
+            ]
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.join(EXPECTED_FILE)).unwrap(),
+            "1 example.txt 142\n2 example-2.txt 281\n"
+        );
+    }
+
+    #[test]
+    fn part_two_reuses_example() {
+        let dir = std::env::temp_dir().join(format!("aoc-extract-reuse-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let sections = [
+            Section {
+                examples: vec!["1 2 3".to_string()],
+                answer: Some("6".to_string()),
+            },
+            Section {
+                examples: vec![],
+                answer: Some("7".to_string()),
+            },
+        ];
+
+        let written = write_fixtures(&dir, &sections).unwrap();
+        assert_eq!(
+            written,
+            vec![dir.join("example.txt"), dir.join(EXPECTED_FILE)]
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.join(EXPECTED_FILE)).unwrap(),
+            "1 example.txt 6\n2 example.txt 7\n"
+        );
+    }
+}
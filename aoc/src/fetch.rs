@@ -0,0 +1,108 @@
+use std::path::{Path, PathBuf};
+
+use crate::client::{Client, HttpClient};
+
+/// Where a fetched input lives on disk.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads the input for a day into `path`, unless it has already been
+/// downloaded, in which case the network is never touched.
+pub fn fetch_input<H: HttpClient>(
+    client: &Client<H>,
+    year: u16,
+    day: u8,
+    path: &Path,
+) -> Result<Fetched, String> {
+    if path.exists() {
+        return Ok(Fetched::Cached(path.to_path_buf()));
+    }
+
+    let input = client.input(year, day)?;
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|err| format!("could not create {}: {err}", dir.display()))?;
+    }
+    std::fs::write(path, input)
+        .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+
+    Ok(Fetched::Downloaded(path.to_path_buf()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct Counting<'a>(&'a Cell<usize>);
+
+    impl HttpClient for Counting<'_> {
+        fn get(&self, _url: &str, _session: &str) -> Result<String, String> {
+            self.0.set(self.0.get() + 1);
+            Ok("puzzle input\n".to_string())
+        }
+
+        fn post(
+            &self,
+            _url: &str,
+            _session: &str,
+            _form: &[(&str, &str)],
+        ) -> Result<String, String> {
+            unreachable!("fetching never posts")
+        }
+    }
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_then_caches() {
+        let calls = Cell::new(0);
+        let client = Client::new(Counting(&calls), "http://stub", Some("abc".to_string()));
+        let path = scratch_dir("cache").join("2025/07/input.txt");
+
+        assert_eq!(
+            fetch_input(&client, 2025, 7, &path),
+            Ok(Fetched::Downloaded(path.clone()))
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "puzzle input\n");
+
+        assert_eq!(
+            fetch_input(&client, 2025, 7, &path),
+            Ok(Fetched::Cached(path.clone()))
+        );
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn cached_without_session() {
+        let calls = Cell::new(0);
+        let client = Client::new(Counting(&calls), "http://stub", None);
+        let path = scratch_dir("no-session").join("input.txt");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "already here").unwrap();
+
+        assert_eq!(
+            fetch_input(&client, 2025, 7, &path),
+            Ok(Fetched::Cached(path.clone()))
+        );
+        assert_eq!(calls.get(), 0);
+    }
+
+    #[test]
+    fn failed_download_is_not_cached() {
+        let calls = Cell::new(0);
+        let client = Client::new(Counting(&calls), "http://stub", None);
+        let path = scratch_dir("failed").join("input.txt");
+
+        assert!(fetch_input(&client, 2025, 7, &path).is_err());
+        assert!(!path.exists());
+    }
+}
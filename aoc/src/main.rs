@@ -0,0 +1,308 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand};
+use utils::{Answer, InputSource, Part};
+
+mod bench;
+mod client;
+mod extract;
+mod fetch;
+mod registry;
+mod scaffold;
+#[cfg(test)]
+mod stub;
+mod submit;
+mod verify;
+
+#[derive(Parser)]
+#[command(about = "Runs Advent of Code solutions from every year")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day's solution against its input
+    Run {
+        year: u16,
+        day: u8,
+        /// Only run this part (1 or 2)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Download a day's input into the inputs directory
+    Fetch { year: u16, day: u8 },
+    /// Submit an answer, computing it from the day's input when not given
+    Submit {
+        year: u16,
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        answer: Option<String>,
+    },
+    /// Check every day against the answers recorded in answers.toml
+    Verify { year: Option<u16>, day: Option<u8> },
+    /// Time each day's parsing and parts against its input
+    Bench {
+        year: Option<u16>,
+        day: Option<u8>,
+        /// Untimed runs before sampling
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        /// Timed runs per part
+        #[arg(long, default_value_t = 20)]
+        samples: usize,
+    },
+    /// Create a crate for a new day and register it with the runner
+    New { year: u16, day: u8 },
+    /// Pull the examples and their answers out of a saved puzzle page
+    Examples {
+        year: u16,
+        day: u8,
+        /// The puzzle description saved as HTML
+        page: PathBuf,
+    },
+    /// List every registered day
+    List,
+}
+
+#[derive(Args)]
+#[group(multiple = false)]
+struct InputArgs {
+    /// Read the input from this file, or `-` for stdin
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Use the day's example, or `example-NAME.txt` when a name is given
+    #[arg(long, num_args = 0..=1, default_missing_value = "")]
+    example: Option<String>,
+}
+
+impl InputArgs {
+    fn source(&self) -> InputSource {
+        match (&self.input, &self.example) {
+            (Some(path), _) if path.as_os_str() == "-" => InputSource::Stdin,
+            (Some(path), _) => InputSource::Path(path.clone()),
+            (None, Some(name)) if name.is_empty() => InputSource::Example(None),
+            (None, Some(name)) => InputSource::Example(Some(name.clone())),
+            (None, None) => InputSource::Default,
+        }
+    }
+}
+
+/// `.aoc` at the workspace root, where the runner keeps its own state away
+/// from the committed inputs.
+fn state_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc to live inside the workspace")
+        .join(".aoc")
+}
+
+fn run(year: u16, day: u8, part: Option<u8>, input: &InputArgs) -> Result<(), String> {
+    let entry = registry::find(year, day).ok_or(format!("{year} day {day} is not registered"))?;
+    let input = utils::read_input(year, day, &input.source()).map_err(|err| err.to_string())?;
+
+    let parts = match part {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let parsed = entry.solution.parse(&input);
+    for part in parts {
+        let answer = entry.solution.solve(parsed.as_ref(), part);
+        println!("Part {part}: {answer}");
+    }
+
+    Ok(())
+}
+
+fn submit(year: u16, day: u8, part: u8, answer: Option<String>) -> Result<(), String> {
+    let part = if part == 1 { Part::One } else { Part::Two };
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let entry =
+                registry::find(year, day).ok_or(format!("{year} day {day} is not registered"))?;
+            let input = utils::read_input(year, day, &InputSource::Default)
+                .map_err(|err| err.to_string())?;
+
+            match entry
+                .solution
+                .solve(entry.solution.parse(&input).as_ref(), part)
+            {
+                Answer::Unsolved => return Err(format!("part {part} is unsolved")),
+                answer => answer.to_string(),
+            }
+        }
+    };
+
+    let mut ledger = submit::Ledger::load(&submit::Ledger::path(year, day))?;
+    let client = client::Client::from_env();
+    let verdict = submit::submit_answer(&client, &mut ledger, year, day, part, &answer)?;
+    println!("Part {part}: {answer} is {verdict}");
+
+    Ok(())
+}
+
+fn verify(year: Option<u16>, day: Option<u8>) -> Result<(), String> {
+    let path = verify::answers_path();
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+    let expected = verify::parse_answers(&contents)?
+        .into_iter()
+        .filter(|answers| year.is_none_or(|year| answers.year == year))
+        .filter(|answers| day.is_none_or(|day| answers.day == day))
+        .collect::<Vec<_>>();
+    let days = registry::select(year, day);
+
+    let mut failed = 0;
+    for report in verify::verify(&days, &expected) {
+        let status = match report.outcome {
+            verify::Outcome::Passed => "ok".to_string(),
+            verify::Outcome::Skipped(reason) => format!("skipped, {reason}"),
+            verify::Outcome::Failed(failures) => {
+                failed += 1;
+                failures.join(", ")
+            }
+        };
+        println!("{} day {:02}: {status}", report.year, report.day);
+    }
+
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{failed} day(s) did not match answers.toml")),
+    }
+}
+
+fn bench(year: Option<u16>, day: Option<u8>, config: bench::Config) -> Result<(), String> {
+    if cfg!(debug_assertions) {
+        eprintln!("note: built without optimisations, use `cargo run --release`");
+    }
+
+    let path = bench::history_path();
+    let mut history = bench::load_history(&path)?;
+    let mut run = bench::Run {
+        commit: bench::current_commit(),
+        timestamp: bench::now(),
+        timings: Vec::new(),
+    };
+
+    println!(
+        "{:<12} {:<7} {:>10} {:>10} {:>10} {:>8}",
+        "day", "stage", "min", "median", "p95", "change"
+    );
+    for entry in registry::select(year, day) {
+        let Some(timings) = bench::bench_day(&entry, &config)? else {
+            println!("{} day {:02}  skipped, no input", entry.year, entry.day);
+            continue;
+        };
+
+        for timing in timings {
+            let change = bench::previous(&history, &run, &timing).map_or("-".to_string(), |old| {
+                let change = (timing.median as f64 / old.median.max(1) as f64 - 1.0) * 100.0;
+                format!("{change:+.1}%")
+            });
+            let nanos = |nanos| format!("{:.2?}", Duration::from_nanos(nanos));
+
+            println!(
+                "{:<12} {:<7} {:>10} {:>10} {:>10} {:>8}",
+                format!("{} day {:02}", timing.year, timing.day),
+                timing.stage.to_string(),
+                nanos(timing.min),
+                nanos(timing.median),
+                nanos(timing.p95),
+                change
+            );
+            run.timings.push(timing);
+        }
+    }
+
+    println!();
+    println!("{:<6} {:>10}", "year", "total");
+    for (year, total) in bench::year_totals(&run.timings) {
+        println!("{year:<6} {:>10}", format!("{total:.2?}"));
+    }
+
+    history.push(run);
+    bench::save_history(&path, &history)
+}
+
+fn new_day(year: u16, day: u8) -> Result<(), String> {
+    for path in scaffold::new_day(&scaffold::workspace_root(), &utils::inputs_dir(), year, day)? {
+        println!("Created {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn examples(year: u16, day: u8, page: &Path) -> Result<(), String> {
+    let page = std::fs::read_to_string(page)
+        .map_err(|err| format!("could not read {}: {err}", page.display()))?;
+
+    let sections = extract::sections(&page);
+    for path in extract::write_fixtures(&utils::day_dir(year, day), &sections)? {
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn fetch(year: u16, day: u8) -> Result<(), String> {
+    let path = InputSource::Default
+        .path(year, day)
+        .expect("the default input to be a file");
+
+    match fetch::fetch_input(&client::Client::from_env(), year, day, &path)? {
+        fetch::Fetched::Cached(path) => println!("Using cached input at {}", path.display()),
+        fetch::Fetched::Downloaded(path) => println!("Downloaded input to {}", path.display()),
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let result = match Cli::parse().command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+        } => run(year, day, part, &input),
+        Command::Fetch { year, day } => fetch(year, day),
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        } => submit(year, day, part, answer),
+        Command::Verify { year, day } => verify(year, day),
+        Command::New { year, day } => new_day(year, day),
+        Command::Examples { year, day, page } => examples(year, day, &page),
+        Command::Bench {
+            year,
+            day,
+            warmup,
+            samples,
+        } => bench(year, day, bench::Config { warmup, samples }),
+        Command::List => {
+            for entry in registry::DAYS {
+                println!("{} day {:02}", entry.year, entry.day);
+            }
+            Ok(())
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
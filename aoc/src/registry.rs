@@ -0,0 +1,69 @@
+use utils::DynSolution;
+
+/// A solved day that the runner knows how to execute.
+#[derive(Clone)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solution: &'static (dyn DynSolution + Sync),
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $solution:path) => {
+        Day {
+            year: $year,
+            day: $day,
+            solution: &$solution,
+        }
+    };
+}
+
+pub static DAYS: &[Day] = &[
+    day!(2023, 1, aoc_2023_day_01::Day01),
+    day!(2023, 2, aoc_2023_day_02::Day02),
+    day!(2023, 3, aoc_2023_day_03::Day03),
+    day!(2023, 4, aoc_2023_day_04::Day04),
+    day!(2023, 5, aoc_2023_day_05::Day05),
+    day!(2023, 6, aoc_2023_day_06::Day06),
+    day!(2023, 7, aoc_2023_day_07::Day07),
+    day!(2023, 8, aoc_2023_day_08::Day08),
+    day!(2023, 9, aoc_2023_day_09::Day09),
+    day!(2023, 10, aoc_2023_day_10::Day10),
+    day!(2023, 18, aoc_2023_day_18::Day18),
+    day!(2023, 19, aoc_2023_day_19::Day19),
+    day!(2023, 20, aoc_2023_day_20::Day20),
+    day!(2024, 1, aoc_2024_day_01::Day01),
+    day!(2024, 2, aoc_2024_day_02::Day02),
+    day!(2024, 3, aoc_2024_day_03::Day03),
+    day!(2024, 4, aoc_2024_day_04::Day04),
+    day!(2024, 5, aoc_2024_day_05::Day05),
+    day!(2024, 6, aoc_2024_day_06::Day06),
+    day!(2024, 7, aoc_2024_day_07::Day07),
+    day!(2024, 8, aoc_2024_day_08::Day08),
+    day!(2024, 9, aoc_2024_day_09::Day09),
+    day!(2024, 10, aoc_2024_day_10::Day10),
+    day!(2024, 11, aoc_2024_day_11::Day11),
+    day!(2024, 12, aoc_2024_day_12::Day12),
+    day!(2024, 13, aoc_2024_day_13::Day13),
+    day!(2024, 14, aoc_2024_day_14::Day14),
+    day!(2025, 1, aoc_2025_day_01::Day01),
+    day!(2025, 2, aoc_2025_day_02::Day02),
+    day!(2025, 3, aoc_2025_day_03::Day03),
+    day!(2025, 4, aoc_2025_day_04::Day04),
+    day!(2025, 5, aoc_2025_day_05::Day05),
+    day!(2025, 6, aoc_2025_day_06::Day06),
+    day!(2025, 7, aoc_2025_day_07::Day07),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+/// Registered days, optionally narrowed down to a year and day.
+pub fn select(year: Option<u16>, day: Option<u8>) -> Vec<Day> {
+    DAYS.iter()
+        .filter(|entry| year.is_none_or(|year| entry.year == year))
+        .filter(|entry| day.is_none_or(|day| entry.day == day))
+        .cloned()
+        .collect()
+}
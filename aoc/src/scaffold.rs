@@ -0,0 +1,255 @@
+use std::path::{Path, PathBuf};
+
+const TEMPLATES: [(&str, &str); 4] = [
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    ("src/part1.rs", include_str!("../templates/part1.rs.tmpl")),
+    ("src/part2.rs", include_str!("../templates/part2.rs.tmpl")),
+];
+
+/// The workspace root, one level above the `aoc` crate.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc to live inside the workspace")
+        .to_path_buf()
+}
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+        .replace("{dd}", &format!("{day:02}"))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|err| format!("could not read {}: {err}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|err| format!("could not create {}: {err}", dir.display()))?;
+    }
+    std::fs::write(path, contents)
+        .map_err(|err| format!("could not write {}: {err}", path.display()))
+}
+
+/// Inserts `line` into the run of lines starting with `prefix`, keeping the
+/// run ordered by `key`. When there is no such run the line goes after
+/// `anchor`.
+fn insert_sorted<K: Ord>(
+    contents: &str,
+    prefix: &str,
+    anchor: &str,
+    line: &str,
+    key: impl Fn(&str) -> K,
+) -> Result<String, String> {
+    let mut lines = contents.lines().collect::<Vec<_>>();
+    if lines.contains(&line) {
+        return Ok(contents.to_string());
+    }
+
+    let position = match lines.iter().position(|l| l.starts_with(prefix)) {
+        Some(start) => {
+            let run = lines[start..]
+                .iter()
+                .take_while(|l| l.starts_with(prefix))
+                .count();
+            start + lines[start..start + run].partition_point(|l| key(l) < key(line))
+        }
+        None => {
+            lines
+                .iter()
+                .position(|&l| l == anchor)
+                .ok_or(format!("could not find `{anchor}`"))?
+                + 1
+        }
+    };
+    lines.insert(position, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The year and day of a `day!(2024, 9, ...)` registry line.
+fn registry_key(line: &str) -> (u32, u32) {
+    let mut numbers = line
+        .trim_start()
+        .trim_start_matches("day!(")
+        .split(',')
+        .map(|num| num.trim().parse().unwrap_or(0));
+
+    (numbers.next().unwrap_or(0), numbers.next().unwrap_or(0))
+}
+
+/// Adds the year to the workspace members when it is not there yet.
+fn register_member(manifest: &str, year: u16) -> Result<String, String> {
+    let member = format!("\"{year}/day-*\"");
+    if manifest.contains(&member) {
+        return Ok(manifest.to_string());
+    }
+
+    let start = manifest
+        .find("members = [")
+        .ok_or("could not find the workspace members")?;
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("unterminated workspace members")?;
+    Ok(format!(
+        "{}, {member}{}",
+        &manifest[..end],
+        &manifest[end..]
+    ))
+}
+
+/// Creates `<year>/day-<dd>` from the templates, registers it with the
+/// workspace, the runner's dependencies and its registry, and creates empty
+/// input, example and expected answer files. Returns the files that were created.
+pub fn new_day(root: &Path, inputs: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day {day} is not between 1 and 25"));
+    }
+
+    let crate_dir = root.join(year.to_string()).join(format!("day-{day:02}"));
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+
+    let mut created = Vec::new();
+    for (name, template) in TEMPLATES {
+        let path = crate_dir.join(name);
+        write(&path, &render(template, year, day))?;
+        created.push(path);
+    }
+
+    let day_inputs = inputs.join(year.to_string()).join(format!("{day:02}"));
+    for name in ["input.txt", "example.txt", utils::EXPECTED_FILE] {
+        let path = day_inputs.join(name);
+        if !path.exists() {
+            write(&path, "")?;
+            created.push(path);
+        }
+    }
+
+    let manifest = root.join("Cargo.toml");
+    write(&manifest, &register_member(&read(&manifest)?, year)?)?;
+
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    let dependency = format!("aoc-{year}-day-{day:02} = {{ path = \"../{year}/day-{day:02}\" }}");
+    let contents = read(&runner_manifest)?;
+    let contents = insert_sorted(
+        &contents,
+        "aoc-",
+        "[dependencies]",
+        &dependency,
+        str::to_owned,
+    )?;
+    write(&runner_manifest, &contents)?;
+
+    let registry = root.join("aoc").join("src").join("registry.rs");
+    let entry = format!("    day!({year}, {day}, aoc_{year}_day_{day:02}::Day{day:02}),");
+    let contents = read(&registry)?;
+    let contents = insert_sorted(
+        &contents,
+        "    day!(",
+        "pub static DAYS: &[Day] = &[",
+        &entry,
+        registry_key,
+    )?;
+    write(&registry, &contents)?;
+
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"[workspace]
+members = ["aoc", "utils", "2024/day-*"]
+"#;
+
+    const RUNNER_MANIFEST: &str = r#"[package]
+name = "aoc"
+
+[dependencies]
+clap = { workspace = true }
+aoc-2024-day-09 = { path = "../2024/day-09" }
+aoc-2024-day-11 = { path = "../2024/day-11" }
+"#;
+
+    const REGISTRY: &str = r#"pub static DAYS: &[Day] = &[
+    day!(2024, 9, aoc_2024_day_09::Day09),
+    day!(2024, 11, aoc_2024_day_11::Day11),
+];
+"#;
+
+    fn scratch_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-new-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        write(&root.join("Cargo.toml"), MANIFEST).unwrap();
+        write(&root.join("aoc/Cargo.toml"), RUNNER_MANIFEST).unwrap();
+        write(&root.join("aoc/src/registry.rs"), REGISTRY).unwrap();
+        root
+    }
+
+    #[test]
+    fn registers_in_order() {
+        let root = scratch_root("order");
+        let inputs = root.join("inputs");
+
+        new_day(&root, &inputs, 2024, 10).unwrap();
+        new_day(&root, &inputs, 2025, 1).unwrap();
+
+        assert_eq!(
+            read(&root.join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"utils\", \"2024/day-*\", \"2025/day-*\"]\n"
+        );
+        assert!(read(&root.join("aoc/Cargo.toml")).unwrap().ends_with(
+            r#"aoc-2024-day-09 = { path = "../2024/day-09" }
+aoc-2024-day-10 = { path = "../2024/day-10" }
+aoc-2024-day-11 = { path = "../2024/day-11" }
+aoc-2025-day-01 = { path = "../2025/day-01" }
+"#
+        ));
+        assert_eq!(
+            read(&root.join("aoc/src/registry.rs")).unwrap(),
+            r#"pub static DAYS: &[Day] = &[
+    day!(2024, 9, aoc_2024_day_09::Day09),
+    day!(2024, 10, aoc_2024_day_10::Day10),
+    day!(2024, 11, aoc_2024_day_11::Day11),
+    day!(2025, 1, aoc_2025_day_01::Day01),
+];
+"#
+        );
+    }
+
+    #[test]
+    fn creates_crate_and_inputs() {
+        let root = scratch_root("files");
+        let inputs = root.join("inputs");
+
+        let created = new_day(&root, &inputs, 2025, 8).unwrap();
+        assert_eq!(created.len(), 7);
+        assert_eq!(read(&inputs.join("2025/08/example.txt")).unwrap(), "");
+
+        let manifest = read(&root.join("2025/day-08/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"aoc-2025-day-08\""));
+
+        let lib = read(&root.join("2025/day-08/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day08;"));
+
+        assert!(lib.contains("utils::check_examples(&Day08, 2025, 8);"));
+    }
+
+    #[test]
+    fn refuses_existing_day() {
+        let root = scratch_root("existing");
+        let inputs = root.join("inputs");
+
+        new_day(&root, &inputs, 2025, 8).unwrap();
+        assert!(new_day(&root, &inputs, 2025, 8).is_err());
+        assert!(new_day(&root, &inputs, 2025, 26).is_err());
+    }
+}
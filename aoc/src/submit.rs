@@ -0,0 +1,394 @@
+use std::fmt::Display;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use utils::Part;
+
+use crate::client::{Client, HttpClient};
+
+/// What the site said about a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// Submitted too soon after the last answer, with the seconds left to wait.
+    Wait(u64),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page returned after posting an answer.
+    pub fn from_page(page: &str) -> Option<Self> {
+        if page.contains("You gave an answer too recently") {
+            let start = page.find("You have ")? + "You have ".len();
+            let end = start + page[start..].find(" left to wait")?;
+            return wait_seconds(&page[start..end]).map(Self::Wait);
+        }
+
+        if page.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if page.contains("your answer is too high") {
+            Some(Self::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Some(Self::TooLow)
+        } else if page.contains("That's not the right answer") {
+            Some(Self::Incorrect)
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Self::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    /// Whether this verdict says anything about the answer itself.
+    fn is_judgement(&self) -> bool {
+        !matches!(self, Self::Wait(_) | Self::WrongLevel)
+    }
+}
+
+/// Parses durations like `1m 23s` or `45s`.
+fn wait_seconds(text: &str) -> Option<u64> {
+    text.split_whitespace().try_fold(0, |total, amount| {
+        let (num, unit) = amount.split_at(amount.len().checked_sub(1)?);
+        let scale = match unit {
+            "h" => 3600,
+            "m" => 60,
+            "s" => 1,
+            _ => return None,
+        };
+        Some(total + num.parse::<u64>().ok()? * scale)
+    })
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Incorrect => write!(f, "incorrect"),
+            Self::Wait(seconds) => write!(f, "wait {seconds}s"),
+            Self::WrongLevel => write!(f, "wrong level"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "too high" => Ok(Self::TooHigh),
+            "too low" => Ok(Self::TooLow),
+            "incorrect" => Ok(Self::Incorrect),
+            "wrong level" => Ok(Self::WrongLevel),
+            _ => s
+                .strip_prefix("wait ")
+                .and_then(wait_seconds)
+                .map(Self::Wait)
+                .ok_or(format!("unknown verdict `{s}`")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted for a day and what the site said about it, kept
+/// as one `part<TAB>verdict<TAB>answer` line per submission.
+pub struct Ledger {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl Ledger {
+    /// `.aoc/ledgers/<year>/<dd>.txt` at the workspace root, next to the
+    /// bench history rather than among the inputs.
+    pub fn path(year: u16, day: u8) -> PathBuf {
+        crate::state_dir()
+            .join("ledgers")
+            .join(year.to_string())
+            .join(format!("{day:02}.txt"))
+    }
+
+    /// Loads the ledger at `path`, which is empty when the file does not exist.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(format!("could not read {}: {err}", path.display())),
+        };
+
+        let entries = contents
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let mut fields = line.splitn(3, '\t');
+                let part = match fields.next() {
+                    Some("1") => Part::One,
+                    Some("2") => Part::Two,
+                    _ => return Err(format!("bad ledger line `{line}`")),
+                };
+                let verdict = fields.next().unwrap_or_default().parse()?;
+                let answer = fields
+                    .next()
+                    .ok_or(format!("bad ledger line `{line}`"))?
+                    .to_string();
+
+                Ok(Entry {
+                    part,
+                    answer,
+                    verdict,
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+
+    /// Refuses answers that are already known to be wrong, either because
+    /// they were rejected before or because they fall outside a too high or
+    /// too low bound.
+    pub fn check(&self, part: Part, answer: &str) -> Result<(), String> {
+        let judged = self
+            .entries
+            .iter()
+            .filter(|entry| entry.part == part && entry.verdict.is_judgement());
+
+        let mut high = None;
+        let mut low = None;
+        for entry in judged {
+            if entry.verdict == Verdict::Correct {
+                return Err(format!(
+                    "part {part} is already solved with {}",
+                    entry.answer
+                ));
+            }
+            if entry.answer == answer {
+                return Err(format!(
+                    "{answer} was already submitted for part {part} and was {}",
+                    entry.verdict
+                ));
+            }
+
+            let Ok(value) = entry.answer.parse::<i128>() else {
+                continue;
+            };
+            match entry.verdict {
+                Verdict::TooHigh => high = Some(high.map_or(value, |high: i128| high.min(value))),
+                Verdict::TooLow => low = Some(low.map_or(value, |low: i128| low.max(value))),
+                _ => {}
+            }
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            if let Some(high) = high.filter(|&high| value >= high) {
+                return Err(format!("{answer} is too high, {high} already was"));
+            }
+            if let Some(low) = low.filter(|&low| value <= low) {
+                return Err(format!("{answer} is too low, {low} already was"));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, entry: Entry) -> Result<(), String> {
+        let write = || {
+            if let Some(dir) = self.path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            let mut file = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+            writeln!(file, "{}\t{}\t{}", entry.part, entry.verdict, entry.answer)
+        };
+        write().map_err(|err| format!("could not write {}: {err}", self.path.display()))?;
+
+        self.entries.push(entry);
+        Ok(())
+    }
+}
+
+/// Submits an answer unless the ledger already knows it is wrong, and
+/// records the verdict.
+pub fn submit_answer<H: HttpClient>(
+    client: &Client<H>,
+    ledger: &mut Ledger,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Verdict, String> {
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        return Err(format!("`{answer}` is not an answer"));
+    }
+    ledger.check(part, answer)?;
+
+    let verdict = client.answer(year, day, part, answer)?;
+    ledger.record(Entry {
+        part,
+        answer: answer.to_string(),
+        verdict: verdict.clone(),
+    })?;
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::UreqClient;
+    use crate::stub::StubServer;
+
+    fn scratch_ledger(name: &str) -> Ledger {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-submit-{name}-{}", std::process::id()))
+            .join("ledger.txt");
+        let _ = std::fs::remove_file(&path);
+        Ledger::load(&path).unwrap()
+    }
+
+    #[test]
+    fn ledger_path() {
+        let path = Ledger::path(2023, 5);
+        assert_eq!(path, crate::state_dir().join("ledgers/2023/05.txt"));
+        assert!(!path.starts_with(utils::day_dir(2023, 5)));
+    }
+
+    fn entry(part: Part, answer: &str, verdict: Verdict) -> Entry {
+        Entry {
+            part,
+            answer: answer.to_string(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn verdicts() {
+        let pages = [
+            (
+                "That's the right answer!  You are one gold star closer.",
+                Some(Verdict::Correct),
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Some(Verdict::TooHigh),
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Some(Verdict::TooLow),
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure you're using the full input data.",
+                Some(Verdict::Incorrect),
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.",
+                Some(Verdict::Wait(83)),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Some(Verdict::WrongLevel),
+            ),
+            ("Something else entirely", None),
+        ];
+
+        for (text, verdict) in pages {
+            let page = format!("<main><article><p>{text}</p></article></main>");
+            assert_eq!(Verdict::from_page(&page), verdict, "{text}");
+        }
+    }
+
+    #[test]
+    fn verdict_round_trip() {
+        for verdict in [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Incorrect,
+            Verdict::Wait(45),
+            Verdict::WrongLevel,
+        ] {
+            assert_eq!(verdict.to_string().parse(), Ok(verdict));
+        }
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let mut ledger = scratch_ledger("refuse");
+        ledger
+            .record(entry(Part::One, "100", Verdict::TooHigh))
+            .unwrap();
+        ledger
+            .record(entry(Part::One, "10", Verdict::TooLow))
+            .unwrap();
+        ledger
+            .record(entry(Part::One, "42", Verdict::Incorrect))
+            .unwrap();
+        ledger
+            .record(entry(Part::One, "50", Verdict::Wait(30)))
+            .unwrap();
+
+        assert!(ledger.check(Part::One, "42").is_err());
+        assert!(ledger.check(Part::One, "100").is_err());
+        assert!(ledger.check(Part::One, "150").is_err());
+        assert!(ledger.check(Part::One, "10").is_err());
+        assert!(ledger.check(Part::One, "3").is_err());
+        assert!(ledger.check(Part::One, "50").is_ok());
+        assert!(ledger.check(Part::One, "99").is_ok());
+        assert!(ledger.check(Part::Two, "150").is_ok());
+
+        ledger
+            .record(entry(Part::One, "64", Verdict::Correct))
+            .unwrap();
+        assert!(ledger.check(Part::One, "65").is_err());
+    }
+
+    #[test]
+    fn ledger_persists() {
+        let mut ledger = scratch_ledger("persist");
+        ledger
+            .record(entry(Part::Two, "abc", Verdict::Incorrect))
+            .unwrap();
+        ledger
+            .record(entry(Part::Two, "12", Verdict::Wait(60)))
+            .unwrap();
+
+        let loaded = Ledger::load(&ledger.path).unwrap();
+        assert_eq!(loaded.entries, ledger.entries);
+    }
+
+    #[test]
+    fn submits_and_records() {
+        let server = StubServer::start(
+            200,
+            "<p>That's not the right answer; your answer is too low.</p>",
+        );
+        let client = Client::new(UreqClient, &server.url, Some("abc".to_string()));
+        let mut ledger = scratch_ledger("submit");
+
+        assert_eq!(
+            submit_answer(&client, &mut ledger, 2025, 7, Part::One, "12"),
+            Ok(Verdict::TooLow)
+        );
+        assert_eq!(ledger.entries, [entry(Part::One, "12", Verdict::TooLow)]);
+
+        assert!(submit_answer(&client, &mut ledger, 2025, 7, Part::One, "11").is_err());
+        assert!(submit_answer(&client, &mut ledger, 2025, 7, Part::One, "").is_err());
+        assert_eq!(server.requests().len(), 1);
+    }
+}
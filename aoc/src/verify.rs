@@ -0,0 +1,216 @@
+use std::path::{Path, PathBuf};
+
+use utils::{InputError, InputSource, Part};
+
+use crate::registry::Day;
+
+/// The confirmed answers for one day's real input.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Expected {
+    pub year: u16,
+    pub day: u8,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    fn part(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    Skipped(&'static str),
+    Failed(Vec<String>),
+}
+
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub outcome: Outcome,
+}
+
+/// `answers.toml` at the workspace root.
+pub fn answers_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc to live inside the workspace")
+        .join("answers.toml")
+}
+
+/// Parses tables like `[2024.01]` with `part1` and `part2` keys holding
+/// either numbers or strings.
+pub fn parse_answers(contents: &str) -> Result<Vec<Expected>, String> {
+    let table = contents
+        .parse::<toml::Table>()
+        .map_err(|err| format!("invalid answers: {err}"))?;
+
+    let mut expected = Vec::new();
+    for (year, days) in table {
+        let year_num = year.parse().map_err(|_| format!("invalid year `{year}`"))?;
+        let days = days
+            .as_table()
+            .ok_or(format!("`{year}` should be a table of days"))?;
+
+        for (day, answers) in days {
+            let day_num = day
+                .parse()
+                .map_err(|_| format!("invalid day `{year}.{day}`"))?;
+            let answer = |key: &str| match answers.get(key) {
+                None => Ok(None),
+                Some(toml::Value::Integer(num)) => Ok(Some(num.to_string())),
+                Some(toml::Value::String(text)) => Ok(Some(text.clone())),
+                Some(_) => Err(format!("`{year}.{day}.{key}` should be a number or string")),
+            };
+
+            expected.push(Expected {
+                year: year_num,
+                day: day_num,
+                part1: answer("part1")?,
+                part2: answer("part2")?,
+            });
+        }
+    }
+
+    expected.sort_by_key(|answers| (answers.year, answers.day));
+    Ok(expected)
+}
+
+fn check(entry: &Day, expected: &Expected) -> Outcome {
+    let input = match utils::read_input(entry.year, entry.day, &InputSource::Default) {
+        Ok(input) => input,
+        Err(InputError::Missing(_)) => return Outcome::Skipped("no input"),
+        Err(err) => return Outcome::Failed(vec![err.to_string()]),
+    };
+
+    let parsed = entry.solution.parse(&input);
+    let failures = [Part::One, Part::Two]
+        .into_iter()
+        .filter_map(|part| {
+            let expected = expected.part(part)?;
+            let actual = entry.solution.solve(parsed.as_ref(), part).to_string();
+
+            (actual != expected).then(|| format!("part {part}: expected {expected}, got {actual}"))
+        })
+        .collect::<Vec<_>>();
+
+    if failures.is_empty() {
+        Outcome::Passed
+    } else {
+        Outcome::Failed(failures)
+    }
+}
+
+/// Runs every day against its real input and compares with the recorded
+/// answers. Recorded answers for days that are not registered fail.
+pub fn verify(days: &[Day], expected: &[Expected]) -> Vec<Report> {
+    let mut reports = days
+        .iter()
+        .map(|entry| {
+            let outcome = expected
+                .iter()
+                .find(|answers| answers.year == entry.year && answers.day == entry.day)
+                .map_or(Outcome::Skipped("no recorded answers"), |answers| {
+                    check(entry, answers)
+                });
+
+            Report {
+                year: entry.year,
+                day: entry.day,
+                outcome,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    reports.extend(
+        expected
+            .iter()
+            .filter(|answers| {
+                !days
+                    .iter()
+                    .any(|entry| entry.year == answers.year && entry.day == answers.day)
+            })
+            .map(|answers| Report {
+                year: answers.year,
+                day: answers.day,
+                outcome: Outcome::Failed(vec!["not registered".to_string()]),
+            }),
+    );
+
+    reports
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::DAYS;
+
+    #[test]
+    fn parse() {
+        let answers = parse_answers(
+            r#"
+[2024.02]
+part1 = 472
+
+[2024.01]
+part1 = 11
+part2 = "abc"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            answers,
+            vec![
+                Expected {
+                    year: 2024,
+                    day: 1,
+                    part1: Some("11".to_string()),
+                    part2: Some("abc".to_string()),
+                },
+                Expected {
+                    year: 2024,
+                    day: 2,
+                    part1: Some("472".to_string()),
+                    part2: None,
+                },
+            ]
+        );
+        assert!(parse_answers("[2024.01]\npart1 = 1.5").is_err());
+    }
+
+    #[test]
+    fn unregistered_answers_fail() {
+        let expected = parse_answers("[1999.01]\npart1 = 1").unwrap();
+        let reports = verify(&[], &expected);
+
+        assert_eq!(reports.len(), 1);
+        assert!(matches!(reports[0].outcome, Outcome::Failed(_)));
+    }
+
+    #[test]
+    fn answers_match() {
+        let contents = std::fs::read_to_string(answers_path()).unwrap();
+        let expected = parse_answers(&contents).unwrap();
+
+        let failures = verify(DAYS, &expected)
+            .into_iter()
+            .filter_map(|report| match report.outcome {
+                Outcome::Failed(failures) => Some(format!(
+                    "{} day {:02}: {}",
+                    report.year,
+                    report.day,
+                    failures.join(", ")
+                )),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
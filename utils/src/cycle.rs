@@ -0,0 +1,191 @@
+//! Finding where a sequence `x0, f(x0), f(f(x0)), ...` starts repeating.
+//! Any step function over a finite set of states ends up in a cycle, with
+//! states a simulation can leave entirely modelled as one that steps to
+//! itself.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The sequence spends `mu` steps leading in, then repeats every `lambda`
+/// steps from `state`, the state at step `mu`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle<S> {
+    pub mu: usize,
+    pub lambda: usize,
+    pub state: S,
+}
+
+impl<S> Cycle<S> {
+    /// The earliest step holding the same state as step `n`.
+    pub fn reduce(&self, n: u64) -> usize {
+        match n.checked_sub(self.mu as u64) {
+            Some(into_cycle) => self.mu + (into_cycle % self.lambda as u64) as usize,
+            None => n as usize,
+        }
+    }
+}
+
+/// Floyd's tortoise and hare. Needs only two states in memory but steps
+/// the sequence about three times as often as [`hashed`].
+pub fn floyd<S: PartialEq + Clone>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle<S> {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let halfway = step(&hare);
+        hare = step(&halfway);
+    }
+
+    // The hare is now a multiple of lambda ahead, so they meet again at
+    // the start of the cycle when the tortoise starts over
+    let mut mu = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        mu += 1;
+    }
+
+    let mut lambda = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        lambda += 1;
+    }
+
+    Cycle {
+        mu,
+        lambda,
+        state: tortoise,
+    }
+}
+
+/// Brent's algorithm. Like [`floyd`] it keeps two states, but it steps the
+/// sequence less often and finds `lambda` directly.
+pub fn brent<S: PartialEq + Clone>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle<S> {
+    // Search successive powers of two for the cycle length
+    let mut power = 1;
+    let mut lambda = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == lambda {
+            tortoise = hare.clone();
+            power *= 2;
+            lambda = 0;
+        }
+        hare = step(&hare);
+        lambda += 1;
+    }
+
+    // With the hare lambda steps ahead they meet at the start of the cycle
+    let mut mu = 0;
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..lambda {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        mu += 1;
+    }
+
+    Cycle {
+        mu,
+        lambda,
+        state: tortoise,
+    }
+}
+
+/// Steps the sequence until a state comes back, remembering every state
+/// seen. Along with the cycle this returns the states from step `0` up to
+/// but not including step `mu + lambda`, where the repeat happens.
+pub fn hashed<S: Hash + Eq + Clone>(start: S, mut step: impl FnMut(&S) -> S) -> (Cycle<S>, Vec<S>) {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+
+    let mu = loop {
+        if let Some(&first) = seen.get(&state) {
+            break first;
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(std::mem::replace(&mut state, next));
+    };
+
+    let cycle = Cycle {
+        mu,
+        lambda: states.len() - mu,
+        state,
+    };
+    (cycle, states)
+}
+
+/// The state after `n` steps, skipping the repeats once a cycle is found so
+/// `n` can be far larger than the number of distinct states.
+pub fn nth_state<S: Hash + Eq + Clone>(start: S, step: impl FnMut(&S) -> S, n: u64) -> S {
+    let (cycle, mut states) = hashed(start, step);
+    states.swap_remove(cycle.reduce(n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn rho() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 2
+        let step = |&x: &u32| if x == 5 { 2 } else { x + 1 };
+        let expected = Cycle {
+            mu: 2,
+            lambda: 4,
+            state: 2,
+        };
+
+        assert_eq!(floyd(0, step), expected);
+        assert_eq!(brent(0, step), expected);
+
+        let (cycle, states) = hashed(0, step);
+        assert_eq!(cycle, expected);
+        assert_eq!(states, vec![0, 1, 2, 3, 4, 5]);
+
+        assert_eq!(cycle.reduce(1), 1);
+        assert_eq!(cycle.reduce(6), 2);
+        assert_eq!(
+            nth_state(0, step, 1_000_000_000_000),
+            2 + ((1_000_000_000_000u64 - 2) % 4) as u32
+        );
+    }
+
+    #[test]
+    fn fixed_point() {
+        // Walking off the end of a track, then staying there
+        let step = |&x: &Option<u8>| x.and_then(|x| x.checked_add(100));
+        let expected = Cycle {
+            mu: 3,
+            lambda: 1,
+            state: None,
+        };
+
+        assert_eq!(floyd(Some(0), step), expected);
+        assert_eq!(brent(Some(0), step), expected);
+        assert_eq!(hashed(Some(0), step).0, expected);
+    }
+
+    proptest! {
+        #[test]
+        fn agree(table in prop::collection::vec(0usize..40, 40), start in 0usize..40, n in 0u64..200) {
+            let step = |&x: &usize| table[x];
+            let (cycle, _) = hashed(start, step);
+
+            prop_assert_eq!(floyd(start, step), cycle.clone());
+            prop_assert_eq!(brent(start, step), cycle);
+
+            let brute = (0..n).fold(start, |x, _| step(&x));
+            prop_assert_eq!(nth_state(start, step, n), brute);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{day_dir, read_input, Answer, DynSolution, InputError, InputSource, Part};
+
+/// File listing the expected answer for each example, one
+/// `part file answer` line per check, e.g. `1 example.txt 142`.
+pub const EXPECTED_FILE: &str = "expected.txt";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub part: Part,
+    pub file: String,
+    pub answer: String,
+}
+
+pub fn parse_expected(contents: &str) -> Result<Vec<Example>, String> {
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let [part, file, answer] = fields[..] else {
+                return Err(format!("expected `part file answer`, got `{line}`"));
+            };
+            let part = match part {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return Err(format!("unknown part `{part}`")),
+            };
+
+            Ok(Example {
+                part,
+                file: file.to_string(),
+                answer: answer.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// The examples recorded for a day in `inputs/<year>/<day>/expected.txt`.
+pub fn examples(year: u16, day: u8) -> Result<Vec<Example>, InputError> {
+    let path = day_dir(year, day).join(EXPECTED_FILE);
+    let contents = read_input(year, day, &InputSource::Path(path.clone()))?;
+
+    parse_expected(&contents).map_err(|err| {
+        InputError::Io(
+            path,
+            std::io::Error::new(std::io::ErrorKind::InvalidData, err),
+        )
+    })
+}
+
+/// Runs a solution against every recorded example of a day, panicking on
+/// the first answer that does not match. Meant to be called from tests.
+pub fn check_examples(solution: &dyn DynSolution, year: u16, day: u8) {
+    let examples = examples(year, day).unwrap_or_else(|err| panic!("{err}"));
+    assert!(
+        !examples.is_empty(),
+        "no examples recorded for {year} day {day}"
+    );
+
+    for example in examples {
+        let input = read_input(
+            year,
+            day,
+            &InputSource::Path(day_dir(year, day).join(&example.file)),
+        )
+        .unwrap_or_else(|err| panic!("{err}"));
+
+        let parsed = solution.parse(&input);
+        let answer = solution.solve(parsed.as_ref(), example.part);
+        assert_ne!(
+            answer,
+            Answer::Unsolved,
+            "part {} is unsolved",
+            example.part
+        );
+        assert_eq!(
+            answer.to_string(),
+            example.answer,
+            "part {} of {}",
+            example.part,
+            example.file
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expected() {
+        let examples = parse_expected("1 example.txt 142\n\n2 example-2.txt abc\n").unwrap();
+
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    part: Part::One,
+                    file: "example.txt".to_string(),
+                    answer: "142".to_string(),
+                },
+                Example {
+                    part: Part::Two,
+                    file: "example-2.txt".to_string(),
+                    answer: "abc".to_string(),
+                },
+            ]
+        );
+        assert!(parse_expected("3 example.txt 1").is_err());
+        assert!(parse_expected("1 example.txt").is_err());
+    }
+}
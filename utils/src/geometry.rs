@@ -0,0 +1,150 @@
+//! Areas and lattice point counts of polygons with integer vertices.
+
+use crate::math::gcd;
+use crate::{Direction, Point2};
+
+/// A closed polygon through lattice points, in the order they are visited.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Polygon {
+    pub vertices: Vec<Point2<i128>>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point2<i128>>) -> Self {
+        Self { vertices }
+    }
+
+    /// The polygon traced by walking each `(direction, length)` step from
+    /// the origin. The walk is expected to end back where it started.
+    pub fn from_steps(steps: impl IntoIterator<Item = (Direction, i128)>) -> Self {
+        let mut current = Point2::default();
+        let vertices = steps
+            .into_iter()
+            .map(|(dir, length)| {
+                let offset = dir.offset();
+                current += Point2::new(offset.x as i128, offset.y as i128) * length;
+                current
+            })
+            .collect();
+
+        Self { vertices }
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Point2<i128>, Point2<i128>)> + '_ {
+        self.vertices
+            .iter()
+            .copied()
+            .zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    /// Twice the enclosed area, which unlike the area itself is always a
+    /// whole number.
+    pub fn double_area(&self) -> i128 {
+        // Shoelace formula
+        self.edges()
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum::<i128>()
+            .abs()
+    }
+
+    /// Lattice points on the outline.
+    pub fn boundary(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| gcd((b.x - a.x).abs(), (b.y - a.y).abs()))
+            .sum()
+    }
+
+    /// Lattice points strictly inside the outline, none when it has no
+    /// area.
+    pub fn interior(&self) -> i128 {
+        let double_area = self.double_area();
+        if self.vertices.len() < 3 || double_area == 0 {
+            return 0;
+        }
+
+        // Pick's theorem, A = i + b / 2 - 1, rearranged for i
+        (double_area - self.boundary()) / 2 + 1
+    }
+
+    /// Lattice points inside or on the outline, which is how many cells a
+    /// loop of cells encloses when the vertices are cell centres.
+    pub fn lattice_points(&self) -> i128 {
+        self.interior() + self.boundary()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn square() {
+        let square = Polygon::from_steps([
+            (Direction::Right, 4),
+            (Direction::Down, 4),
+            (Direction::Left, 4),
+            (Direction::Up, 4),
+        ]);
+
+        assert_eq!(square.double_area(), 32);
+        assert_eq!(square.boundary(), 16);
+        assert_eq!(square.interior(), 9);
+        assert_eq!(square.lattice_points(), 25);
+    }
+
+    #[test]
+    fn triangle() {
+        // Counter clockwise with a diagonal edge.
+        let triangle = Polygon::new(vec![
+            Point2::new(0, 0),
+            Point2::new(0, 4),
+            Point2::new(6, 0),
+        ]);
+
+        assert_eq!(triangle.double_area(), 24);
+        assert_eq!(triangle.boundary(), 12);
+        assert_eq!(triangle.interior(), 7);
+        assert_eq!(
+            Polygon::from_steps([
+                (Direction::DownRight, 3),
+                (Direction::Left, 3),
+                (Direction::Up, 3),
+            ])
+            .lattice_points(),
+            10
+        );
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(Polygon::default().interior(), 0);
+        assert_eq!(Polygon::new(vec![Point2::new(2, 3)]).interior(), 0);
+        assert_eq!(
+            Polygon::from_steps([(Direction::Right, 5), (Direction::Left, 5)]).interior(),
+            0
+        );
+
+        // Three points on one line enclose nothing either
+        let line = Polygon::new(vec![
+            Point2::new(0, 0),
+            Point2::new(2, 2),
+            Point2::new(4, 4),
+        ]);
+        assert_eq!(line.double_area(), 0);
+        assert_eq!(line.interior(), 0);
+    }
+
+    #[test]
+    fn huge() {
+        let side = 1 << 40;
+        let square = Polygon::from_steps([
+            (Direction::Right, side),
+            (Direction::Down, side),
+            (Direction::Left, side),
+            (Direction::Up, side),
+        ]);
+
+        assert_eq!(square.double_area(), 2 * side * side);
+        assert_eq!(square.lattice_points(), (side + 1) * (side + 1));
+    }
+}
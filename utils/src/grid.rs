@@ -1,4 +1,6 @@
-#[derive(Copy, Clone)]
+use crate::Point2;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Direction {
     Up,
     UpLeft,
@@ -10,7 +12,79 @@ pub enum Direction {
     Right,
 }
 
-pub struct Position(usize, usize);
+impl Direction {
+    /// Every direction, clockwise from `Up`.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// The four directions that are not diagonal, clockwise from `Up`.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The step taken when moving in this direction, with `y` growing
+    /// downwards.
+    pub fn offset(self) -> Point2<i64> {
+        match self {
+            Direction::Up => Point2::new(0, -1),
+            Direction::UpLeft => Point2::new(-1, -1),
+            Direction::UpRight => Point2::new(1, -1),
+            Direction::Down => Point2::new(0, 1),
+            Direction::DownLeft => Point2::new(-1, 1),
+            Direction::DownRight => Point2::new(1, 1),
+            Direction::Left => Point2::new(-1, 0),
+            Direction::Right => Point2::new(1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// Rotates by 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        let index = Self::ALL.iter().position(|&dir| dir == self).unwrap();
+        Self::ALL[(index + 2) % 8]
+    }
+
+    /// Rotates by 90 degrees counter clockwise.
+    pub fn turn_left(self) -> Self {
+        let index = Self::ALL.iter().position(|&dir| dir == self).unwrap();
+        Self::ALL[(index + 6) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        let offset = self.offset();
+        offset.x != 0 && offset.y != 0
+    }
+}
+
+impl TryFrom<Point2<i64>> for Direction {
+    type Error = Point2<i64>;
+
+    /// The direction whose [`Direction::offset`] is `offset`.
+    fn try_from(offset: Point2<i64>) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|dir| dir.offset() == offset)
+            .ok_or(offset)
+    }
+}
+
+/// An `(x, y)` cell in a [`Grid`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position(pub usize, pub usize);
 
 impl Position {
     pub fn new(x: usize, y: usize) -> Self {
@@ -23,50 +97,23 @@ impl Position {
 }
 
 impl Position {
+    /// The neighbouring position in `dir`, `None` when it would need a
+    /// negative coordinate.
     pub fn new_in_dir(&self, dir: Direction) -> Option<Self> {
-        match dir {
-            Direction::Up => {
-                if self.1 == 0 {
-                    None
-                } else {
-                    Some(Self(self.0, self.1 - 1))
-                }
-            }
-            Direction::UpLeft => {
-                if self.1 == 0 || self.0 == 0 {
-                    None
-                } else {
-                    Some(Self(self.0 - 1, self.1 - 1))
-                }
-            }
-            Direction::UpRight => {
-                if self.1 == 0 {
-                    None
-                } else {
-                    Some(Self(self.0, self.1 - 1))
-                }
-            }
-            Direction::Down => Some(Self(self.0, self.1 + 1)),
-            Direction::DownLeft => {
-                if self.0 == 0 {
-                    None
-                } else {
-                    Some(Self(self.0 - 1, self.1 + 1))
-                }
-            }
-            Direction::DownRight => Some(Self(self.0 + 1, self.1 + 1)),
-            Direction::Left => {
-                if self.0 == 0 {
-                    None
-                } else {
-                    Some(Self(self.0 - 1, self.1))
-                }
-            }
-            Direction::Right => Some(Self(self.0 + 1, self.1)),
-        }
+        Point2::from(*self).step(dir).try_into().ok()
     }
 }
 
+/// What happens to neighbours that fall off the edge of a [`Grid`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Edges {
+    /// They are skipped.
+    Clip,
+    /// They wrap around to the other side, as if the grid were a torus.
+    Wrap,
+}
+
+#[derive(Clone)]
 pub struct Grid<T> {
     width: usize,
     height: usize,
@@ -95,6 +142,17 @@ where
     }
 }
 
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
 pub struct GridCoordIter<'a, T> {
     grid: &'a Grid<T>,
     index: usize,
@@ -144,6 +202,13 @@ impl<T> Grid<T> {
         }
     }
 
+    /// The value at `point`, `None` when it is outside the grid.
+    pub fn at(&self, point: Point2<i64>) -> Option<&T> {
+        point
+            .to_index(self.width, self.height)
+            .map(|index| &self.cells[index])
+    }
+
     pub fn get(&self, coord: &Position) -> Option<&T> {
         let (x, y) = coord.inner();
 
@@ -169,6 +234,105 @@ impl<T> Grid<T> {
         }
     }
 
+    pub fn get_mut(&mut self, coord: &Position) -> Option<&mut T> {
+        let (x, y) = coord.inner();
+
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    fn position_of(&self, index: usize) -> Position {
+        Position(index % self.width, index / self.width)
+    }
+
+    /// The index of `coord` moved by `offset`, following `edges` when that
+    /// leaves the grid.
+    fn offset_index(&self, coord: Position, offset: Point2<i64>, edges: Edges) -> Option<usize> {
+        let point = Point2::from(coord) + offset;
+
+        match edges {
+            Edges::Clip => point.to_index(self.width, self.height),
+            Edges::Wrap if self.cells.is_empty() => None,
+            Edges::Wrap => Point2::new(
+                point.x.rem_euclid(self.width as i64),
+                point.y.rem_euclid(self.height as i64),
+            )
+            .to_index(self.width, self.height),
+        }
+    }
+
+    /// The cells at each of `offsets` from `coord`, along with where they
+    /// are. Wrapping on small grids can yield the same cell more than once.
+    pub fn stencil<'a>(
+        &'a self,
+        coord: Position,
+        offsets: impl IntoIterator<Item = Point2<i64>> + 'a,
+        edges: Edges,
+    ) -> impl Iterator<Item = (Position, &'a T)> + 'a {
+        offsets.into_iter().filter_map(move |offset| {
+            self.offset_index(coord, offset, edges)
+                .map(|index| (self.position_of(index), &self.cells[index]))
+        })
+    }
+
+    /// Mutable version of [`Grid::stencil`]. Each cell is yielded once, in
+    /// row major order rather than the order of `offsets`.
+    pub fn stencil_mut(
+        &mut self,
+        coord: Position,
+        offsets: impl IntoIterator<Item = Point2<i64>>,
+        edges: Edges,
+    ) -> impl Iterator<Item = (Position, &mut T)> {
+        let mut indices = offsets
+            .into_iter()
+            .filter_map(|offset| self.offset_index(coord, offset, edges))
+            .collect::<Vec<_>>();
+        indices.sort_unstable();
+        indices.dedup();
+
+        let width = self.width;
+        let mut rest = self.cells.as_mut_slice();
+        let mut start = 0;
+        indices.into_iter().map(move |index| {
+            let (cell, tail) = std::mem::take(&mut rest)[index - start..]
+                .split_first_mut()
+                .expect("index to be inside the grid");
+            rest = tail;
+            start = index + 1;
+
+            (Position(index % width, index / width), cell)
+        })
+    }
+
+    /// The up to four cells directly above, below, left and right of `coord`.
+    pub fn neighbours4(&self, coord: Position) -> impl Iterator<Item = (Position, &T)> {
+        self.stencil(
+            coord,
+            Direction::CARDINAL.map(Direction::offset),
+            Edges::Clip,
+        )
+    }
+
+    /// The up to eight cells around `coord`, including diagonals.
+    pub fn neighbours8(&self, coord: Position) -> impl Iterator<Item = (Position, &T)> {
+        self.stencil(coord, Direction::ALL.map(Direction::offset), Edges::Clip)
+    }
+
+    pub fn neighbours4_mut(&mut self, coord: Position) -> impl Iterator<Item = (Position, &mut T)> {
+        self.stencil_mut(
+            coord,
+            Direction::CARDINAL.map(Direction::offset),
+            Edges::Clip,
+        )
+    }
+
+    pub fn neighbours8_mut(&mut self, coord: Position) -> impl Iterator<Item = (Position, &mut T)> {
+        self.stencil_mut(coord, Direction::ALL.map(Direction::offset), Edges::Clip)
+    }
+
     pub fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = &T> {
         const DIRECTIONS: [Direction; 8] = [
             Direction::Up,
@@ -188,3 +352,146 @@ impl<T> Grid<T> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_in_dir() {
+        let expected = [
+            (Direction::Up, Some(Position(5, 4))),
+            (Direction::UpRight, Some(Position(6, 4))),
+            (Direction::Right, Some(Position(6, 5))),
+            (Direction::DownRight, Some(Position(6, 6))),
+            (Direction::Down, Some(Position(5, 6))),
+            (Direction::DownLeft, Some(Position(4, 6))),
+            (Direction::Left, Some(Position(4, 5))),
+            (Direction::UpLeft, Some(Position(4, 4))),
+        ];
+
+        for (dir, position) in expected {
+            assert_eq!(Position(5, 5).new_in_dir(dir), position, "{dir:?}");
+        }
+    }
+
+    #[test]
+    fn new_in_dir_at_origin() {
+        for dir in Direction::ALL {
+            let offset = dir.offset();
+            let expected = (offset.x >= 0 && offset.y >= 0)
+                .then_some(Position(offset.x as usize, offset.y as usize));
+
+            assert_eq!(Position(0, 0).new_in_dir(dir), expected, "{dir:?}");
+        }
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::<char>::from("abc\ndef\nghi");
+
+        let mut around = grid.neighbours(1, 1).copied().collect::<Vec<_>>();
+        around.sort();
+        assert_eq!(around, vec!['a', 'b', 'c', 'd', 'f', 'g', 'h', 'i']);
+
+        let mut corner = grid.neighbours(2, 0).copied().collect::<Vec<_>>();
+        corner.sort();
+        assert_eq!(corner, vec!['b', 'e', 'f']);
+
+        assert_eq!(grid.at(Point2::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.at(Point2::new(-1, 1)), None);
+    }
+
+    #[test]
+    fn neighbours_with_positions() {
+        let grid = Grid::<char>::from("abc\ndef\nghi");
+
+        assert_eq!(
+            grid.neighbours4(Position(1, 1)).collect::<Vec<_>>(),
+            vec![
+                (Position(1, 0), &'b'),
+                (Position(2, 1), &'f'),
+                (Position(1, 2), &'h'),
+                (Position(0, 1), &'d'),
+            ]
+        );
+        assert_eq!(
+            grid.neighbours4(Position(0, 0)).collect::<Vec<_>>(),
+            vec![(Position(1, 0), &'b'), (Position(0, 1), &'d')]
+        );
+        assert_eq!(
+            grid.neighbours8(Position(2, 2)).collect::<Vec<_>>(),
+            vec![
+                (Position(2, 1), &'f'),
+                (Position(1, 2), &'h'),
+                (Position(1, 1), &'e'),
+            ]
+        );
+        assert_eq!(grid.neighbours8(Position(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn stencil() {
+        let grid = Grid::<char>::from("abc\ndef\nghi");
+        let knight = [Point2::new(1, 2), Point2::new(2, 1), Point2::new(-1, -2)];
+
+        assert_eq!(
+            grid.stencil(Position(0, 0), knight, Edges::Clip)
+                .collect::<Vec<_>>(),
+            vec![(Position(1, 2), &'h'), (Position(2, 1), &'f')]
+        );
+        assert_eq!(
+            grid.stencil(Position(0, 0), knight, Edges::Wrap)
+                .collect::<Vec<_>>(),
+            vec![
+                (Position(1, 2), &'h'),
+                (Position(2, 1), &'f'),
+                (Position(2, 1), &'f'),
+            ]
+        );
+    }
+
+    #[test]
+    fn wrapping() {
+        let grid = Grid::<char>::from("abc\ndef\nghi");
+        let around = grid
+            .stencil(
+                Position(0, 0),
+                Direction::ALL.map(Direction::offset),
+                Edges::Wrap,
+            )
+            .map(|(_, ch)| *ch)
+            .collect::<String>();
+
+        assert_eq!(around, "ghbedfci");
+    }
+
+    #[test]
+    fn mutable_neighbours() {
+        let mut grid = Grid::<char>::from("abc\ndef\nghi");
+        for (_, cell) in grid.neighbours4_mut(Position(1, 1)) {
+            *cell = cell.to_ascii_uppercase();
+        }
+        for (position, cell) in grid.neighbours8_mut(Position(0, 0)) {
+            assert_ne!(position, Position(0, 0));
+            *cell = '#';
+        }
+
+        let cells = grid
+            .iter_with_coords()
+            .map(|(_, ch)| *ch)
+            .collect::<String>();
+        assert_eq!(cells, "a#c##FgHi");
+
+        let mut wrapped = Grid::<char>::from("ab");
+        let cells = wrapped
+            .stencil_mut(
+                Position(0, 0),
+                Direction::ALL.map(Direction::offset),
+                Edges::Wrap,
+            )
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+        assert_eq!(cells, vec![Position(0, 0), Position(1, 0)]);
+    }
+}
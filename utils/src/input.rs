@@ -0,0 +1,158 @@
+use std::fmt::Display;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Overrides the directory puzzle inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a puzzle input should be read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// `inputs/<year>/<day>/input.txt`
+    Default,
+    /// `inputs/<year>/<day>/example.txt`, or `example-<name>.txt` when named
+    Example(Option<String>),
+    Path(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Io(PathBuf, std::io::Error),
+    Stdin(std::io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing(path) => write!(f, "no input found at {}", path.display()),
+            Self::Io(path, err) => write!(f, "could not read {}: {err}", path.display()),
+            Self::Stdin(err) => write!(f, "could not read stdin: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// The root of the `inputs/<year>/<day>/` tree. Uses `AOC_INPUT_DIR` when set,
+/// otherwise the `inputs` directory at the workspace root.
+pub fn inputs_dir() -> PathBuf {
+    match std::env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("utils to live inside the workspace")
+            .join("inputs"),
+    }
+}
+
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    inputs_dir()
+        .join(year.to_string())
+        .join(format!("{day:02}"))
+}
+
+impl InputSource {
+    /// The file this source reads from, `None` for stdin.
+    pub fn path(&self, year: u16, day: u8) -> Option<PathBuf> {
+        match self {
+            Self::Default => Some(day_dir(year, day).join("input.txt")),
+            Self::Example(None) => Some(day_dir(year, day).join("example.txt")),
+            Self::Example(Some(name)) => {
+                Some(day_dir(year, day).join(format!("example-{name}.txt")))
+            }
+            Self::Path(path) => Some(path.clone()),
+            Self::Stdin => None,
+        }
+    }
+}
+
+pub fn read_input(year: u16, day: u8, source: &InputSource) -> Result<String, InputError> {
+    let Some(path) = source.path(year, day) else {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(InputError::Stdin)?;
+
+        return Ok(input);
+    };
+
+    std::fs::read_to_string(&path).map_err(|err| match err.kind() {
+        std::io::ErrorKind::NotFound => InputError::Missing(path),
+        _ => InputError::Io(path, err),
+    })
+}
+
+/// Picks the input source from the command line arguments of a standalone
+/// binary: nothing for the day's input, `-` for stdin, `--example [NAME]`,
+/// or a path to a file.
+pub fn source_from_args(mut args: impl Iterator<Item = String>) -> InputSource {
+    match args.next().as_deref() {
+        None => InputSource::Default,
+        Some("-") => InputSource::Stdin,
+        Some("--example") => InputSource::Example(args.next()),
+        Some(path) => InputSource::Path(PathBuf::from(path)),
+    }
+}
+
+/// Reads the input chosen on the command line, exiting with the error when
+/// it cannot be read.
+pub fn input_from_args(year: u16, day: u8) -> String {
+    let source = source_from_args(std::env::args().skip(1));
+
+    read_input(year, day, &source).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_paths() {
+        let dir = day_dir(2024, 3);
+        assert!(dir.ends_with("inputs/2024/03"));
+        assert_eq!(
+            InputSource::Default.path(2024, 3),
+            Some(dir.join("input.txt"))
+        );
+        assert_eq!(
+            InputSource::Example(None).path(2024, 3),
+            Some(dir.join("example.txt"))
+        );
+        assert_eq!(
+            InputSource::Example(Some("2".to_string())).path(2024, 3),
+            Some(dir.join("example-2.txt"))
+        );
+        assert_eq!(InputSource::Stdin.path(2024, 3), None);
+    }
+
+    #[test]
+    fn args() {
+        let args = |list: &[&str]| source_from_args(list.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(args(&[]), InputSource::Default);
+        assert_eq!(args(&["-"]), InputSource::Stdin);
+        assert_eq!(args(&["--example"]), InputSource::Example(None));
+        assert_eq!(
+            args(&["--example", "larger"]),
+            InputSource::Example(Some("larger".to_string()))
+        );
+        assert_eq!(
+            args(&["mine.txt"]),
+            InputSource::Path(PathBuf::from("mine.txt"))
+        );
+    }
+
+    #[test]
+    fn missing_file() {
+        let source = InputSource::Path(PathBuf::from("does/not/exist.txt"));
+        let err = read_input(2024, 1, &source).unwrap_err();
+
+        assert!(matches!(err, InputError::Missing(_)));
+        assert_eq!(err.to_string(), "no input found at does/not/exist.txt");
+    }
+}
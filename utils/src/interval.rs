@@ -0,0 +1,324 @@
+use std::fmt::Debug;
+use std::ops::{Add, RangeInclusive, Sub};
+
+/// Integers that can bound an [`IntervalSet`].
+pub trait IntervalBound: Copy + Ord + Debug + Add<Output = Self> + Sub<Output = Self> {
+    /// The next value up, `None` at the maximum.
+    fn next(self) -> Option<Self>;
+
+    /// The next value down, `None` at the minimum.
+    fn prev(self) -> Option<Self>;
+
+    /// How many steps apart two values are, which fits even when the
+    /// difference itself doesn't.
+    fn distance(self, other: Self) -> u128;
+}
+
+macro_rules! interval_bound {
+    ($($int:ty),*) => {
+        $(impl IntervalBound for $int {
+            fn next(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn prev(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn distance(self, other: Self) -> u128 {
+                self.abs_diff(other) as u128
+            }
+        })*
+    };
+}
+
+interval_bound!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A set of integers stored as sorted closed intervals. Overlapping and
+/// touching intervals are merged as they are inserted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: IntervalBound> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value in `range`. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // Everything ending right before `start` or later touches it, as
+        // does everything starting right after `end` or earlier.
+        let first = self
+            .intervals
+            .partition_point(|&(_, e)| e.next().is_some_and(|next| next < start));
+        let last = self
+            .intervals
+            .partition_point(|&(s, _)| end.next().is_none_or(|next| s <= next));
+
+        let merged = if first < last {
+            (
+                self.intervals[first].0.min(start),
+                self.intervals[last - 1].1.max(end),
+            )
+        } else {
+            (start, end)
+        };
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|&(_, e)| e < value);
+        self.intervals
+            .get(index)
+            .is_some_and(|&(start, _)| start <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many values are in the set, counted in a `u128` so that sets
+    /// wider than `T` can hold still fit. Only a set of every `u128` or
+    /// `i128` is too big, and saturates at `u128::MAX`.
+    pub fn total_len(&self) -> u128 {
+        self.intervals.iter().fold(0u128, |total, &(start, end)| {
+            total.saturating_add(start.distance(end)).saturating_add(1)
+        })
+    }
+
+    /// The merged intervals in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.iter().map(|&(start, end)| start..=end)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in other.iter() {
+            union.insert(range);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Vec::new();
+        let (mut a, mut b) = (self.intervals.iter(), other.intervals.iter());
+        let (mut left, mut right) = (a.next(), b.next());
+
+        while let (Some(&(ls, le)), Some(&(rs, re))) = (left, right) {
+            let (start, end) = (ls.max(rs), le.min(re));
+            if start <= end {
+                intersection.push((start, end));
+            }
+
+            // Whichever ends first cannot overlap anything else
+            if le < re {
+                left = a.next();
+            } else {
+                right = b.next();
+            }
+        }
+
+        Self {
+            intervals: intersection,
+        }
+    }
+
+    /// The values in `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = Vec::new();
+
+        for &(start, end) in &self.intervals {
+            let first = other.intervals.partition_point(|&(_, e)| e < start);
+            let mut current = Some(start);
+
+            for &(os, oe) in other.intervals[first..]
+                .iter()
+                .take_while(|(s, _)| *s <= end)
+            {
+                let Some(from) = current else {
+                    break;
+                };
+                if from < os {
+                    difference.push((from, os.prev().expect("to be above `from`")));
+                }
+                current = oe.next();
+            }
+
+            if let Some(from) = current.filter(|&from| from <= end) {
+                difference.push((from, end));
+            }
+        }
+
+        Self {
+            intervals: difference,
+        }
+    }
+
+    /// Moves each value inside one of the `pieces` source ranges to the same
+    /// place in the range starting at its destination. Values outside every
+    /// source range are kept as they are. When sources overlap the first one
+    /// listed wins.
+    pub fn map_pieces(&self, pieces: &[(RangeInclusive<T>, T)]) -> Self {
+        let mut unmapped = self.clone();
+        let mut mapped = Self::new();
+
+        for (source, destination) in pieces {
+            let offset = |value: T| *destination + (value - *source.start());
+            let source = IntervalSet::from_iter([source.clone()]);
+
+            for range in unmapped.intersection(&source).iter() {
+                let (start, end) = range.into_inner();
+                mapped.insert(offset(start)..=offset(end));
+            }
+            unmapped = unmapped.difference(&source);
+        }
+
+        mapped.union(&unmapped)
+    }
+}
+
+impl<T: IntervalBound> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn merges() {
+        let set = IntervalSet::from_iter([
+            3..=5,
+            10..=14,
+            16..=20,
+            12..=18,
+            0..=0,
+            1..=1,
+            RangeInclusive::new(7, 6),
+        ]);
+
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..=1, 3..=5, 10..=20]);
+        assert_eq!(set.total_len(), 16);
+        assert!(set.contains(0) && set.contains(15) && !set.contains(2) && !set.contains(21));
+    }
+
+    #[test]
+    fn extremes() {
+        let mut set = IntervalSet::from_iter([0..=0, u8::MAX..=u8::MAX]);
+        assert_eq!(
+            set.difference(&IntervalSet::from_iter([0..=u8::MAX])),
+            IntervalSet::new()
+        );
+
+        assert_eq!(set.total_len(), 2);
+
+        set.insert(1..=254);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..=u8::MAX]);
+        assert_eq!(set.total_len(), 256);
+        assert_eq!(IntervalSet::from_iter([i8::MIN..=i8::MAX]).total_len(), 256);
+        assert_eq!(
+            IntervalSet::from_iter([i64::MIN..=-1, 0..=i64::MAX]).total_len(),
+            1 << 64
+        );
+        assert_eq!(
+            IntervalSet::from_iter([u128::MIN..=u128::MAX]).total_len(),
+            u128::MAX
+        );
+        assert_eq!(
+            IntervalSet::from_iter([i8::MIN..=i8::MAX])
+                .difference(&IntervalSet::from_iter([-1..=1]))
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![i8::MIN..=-2, 2..=i8::MAX]
+        );
+    }
+
+    #[test]
+    fn pieces() {
+        // The first map of 2023 day 5's example
+        let seeds = IntervalSet::from_iter([79..=92, 55..=67]);
+        let mapped = seeds.map_pieces(&[(98..=99, 50), (50..=97, 52)]);
+
+        assert_eq!(mapped.iter().collect::<Vec<_>>(), vec![57..=69, 81..=94]);
+    }
+
+    fn ranges() -> impl Strategy<Value = Vec<RangeInclusive<u16>>> {
+        prop::collection::vec(
+            (0u16..256, 0u16..16).prop_map(|(start, len)| start..=start + len),
+            0..8,
+        )
+    }
+
+    fn brute_force(ranges: &[RangeInclusive<u16>]) -> BTreeSet<u16> {
+        ranges.iter().flat_map(|range| range.clone()).collect()
+    }
+
+    fn values(set: &IntervalSet<u16>) -> BTreeSet<u16> {
+        set.iter().flatten().collect()
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(a in ranges(), b in ranges()) {
+            let (set_a, set_b) = (IntervalSet::from_iter(a.clone()), IntervalSet::from_iter(b.clone()));
+            let (brute_a, brute_b) = (brute_force(&a), brute_force(&b));
+
+            // Stored sorted, without overlapping or touching intervals
+            let intervals = set_a.iter().collect::<Vec<_>>();
+            for pair in intervals.windows(2) {
+                prop_assert!(pair[0].end() + 1 < *pair[1].start());
+            }
+
+            prop_assert_eq!(values(&set_a), brute_a.clone());
+            prop_assert_eq!(set_a.total_len(), brute_a.len() as u128);
+            for value in 0..300 {
+                prop_assert_eq!(set_a.contains(value), brute_a.contains(&value));
+            }
+
+            prop_assert_eq!(values(&set_a.union(&set_b)), &brute_a | &brute_b);
+            prop_assert_eq!(values(&set_a.intersection(&set_b)), &brute_a & &brute_b);
+            prop_assert_eq!(values(&set_a.difference(&set_b)), &brute_a - &brute_b);
+        }
+
+        #[test]
+        fn maps_like_brute_force(a in ranges(), sources in ranges(), destinations in prop::collection::vec(0u16..256, 8)) {
+            let pieces = sources.into_iter().zip(destinations).collect::<Vec<_>>();
+            let mapped = IntervalSet::from_iter(a.clone()).map_pieces(&pieces);
+
+            let expected = brute_force(&a)
+                .into_iter()
+                .map(|value| {
+                    pieces
+                        .iter()
+                        .find(|(source, _)| source.contains(&value))
+                        .map_or(value, |(source, destination)| destination + (value - source.start()))
+                })
+                .collect::<BTreeSet<_>>();
+
+            prop_assert_eq!(values(&mapped), expected);
+        }
+    }
+}
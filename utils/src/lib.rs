@@ -1,2 +1,20 @@
+pub mod cycle;
+mod examples;
+pub mod geometry;
 mod grid;
+mod input;
+mod interval;
+pub mod linalg;
+pub mod math;
+pub mod memo;
+mod point;
+mod region;
+pub mod search;
+mod solution;
+pub use examples::*;
 pub use grid::*;
+pub use input::*;
+pub use interval::*;
+pub use point::*;
+pub use region::*;
+pub use solution::*;
@@ -0,0 +1,632 @@
+//! Exact linear systems over integers, solved with rationals so nothing is
+//! lost to rounding.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::math::{crt, extended_gcd, gcd, lcm};
+
+/// A fraction kept in lowest terms with a positive denominator.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { num: 0, den: 1 };
+    pub const ONE: Rational = Rational { num: 1, den: 1 };
+
+    pub fn new(num: i128, den: i128) -> Self {
+        assert!(den != 0, "denominator is zero");
+        let divisor = gcd(num, den) * den.signum();
+        Self {
+            num: num / divisor,
+            den: den / divisor,
+        }
+    }
+
+    pub fn numerator(self) -> i128 {
+        self.num
+    }
+
+    pub fn denominator(self) -> i128 {
+        self.den
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.num == 0
+    }
+
+    /// The value as an integer, `None` when it is not a whole number.
+    pub fn to_integer(self) -> Option<i128> {
+        (self.den == 1).then_some(self.num)
+    }
+
+    /// The largest integer no bigger than the value.
+    pub fn floor(self) -> i128 {
+        self.num.div_euclid(self.den)
+    }
+
+    /// The smallest integer no less than the value.
+    pub fn ceil(self) -> i128 {
+        -(-self).floor()
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Self { num: value, den: 1 }
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.den {
+            1 => write!(f, "{}", self.num),
+            den => write!(f, "{}/{den}", self.num),
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.num * rhs.den + rhs.num * self.den, self.den * rhs.den)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.num * rhs.num, self.den * rhs.den)
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(self.num * rhs.den, self.den * rhs.num)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.num * other.den).cmp(&(other.num * self.den))
+    }
+}
+
+/// The determinant of a square matrix, using fraction free elimination so
+/// every step stays an integer.
+pub fn determinant(matrix: &[Vec<i128>]) -> i128 {
+    let n = matrix.len();
+    let mut m = matrix.to_vec();
+    let mut sign = 1;
+    let mut previous = 1;
+
+    for k in 0..n {
+        let Some(pivot) = (k..n).find(|&row| m[row][k] != 0) else {
+            return 0;
+        };
+        if pivot != k {
+            m.swap(pivot, k);
+            sign = -sign;
+        }
+
+        // Bareiss: each entry divides exactly by the previous pivot
+        for i in k + 1..n {
+            for j in k + 1..n {
+                m[i][j] = (m[i][j] * m[k][k] - m[i][k] * m[k][j]) / previous;
+            }
+        }
+        previous = m[k][k];
+    }
+
+    match n {
+        0 => 1,
+        _ => sign * m[n - 1][n - 1],
+    }
+}
+
+/// Solves a square system with Cramer's rule, `None` when the determinant
+/// is zero and there is no single solution.
+pub fn cramer(matrix: &[Vec<i128>], rhs: &[i128]) -> Option<Vec<Rational>> {
+    let det = determinant(matrix);
+    if det == 0 {
+        return None;
+    }
+
+    let solution = (0..matrix.len())
+        .map(|column| {
+            let replaced = matrix
+                .iter()
+                .zip(rhs)
+                .map(|(row, &value)| {
+                    let mut row = row.clone();
+                    row[column] = value;
+                    row
+                })
+                .collect::<Vec<_>>();
+            Rational::new(determinant(&replaced), det)
+        })
+        .collect();
+
+    Some(solution)
+}
+
+/// How many solutions a system has.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Solutions {
+    None,
+    Unique(Vec<Rational>),
+    /// One solution for every choice of the `free` variables. `particular`
+    /// is the one where they are all zero.
+    Infinite {
+        particular: Vec<Rational>,
+        free: Vec<usize>,
+    },
+}
+
+/// A system in reduced row echelon form, where the row at each index of
+/// `pivots` solves for that variable.
+struct Reduced {
+    rows: Vec<Vec<Rational>>,
+    pivots: Vec<usize>,
+    free: Vec<usize>,
+}
+
+impl Reduced {
+    /// Gauss-Jordan elimination of `matrix * x = rhs`, `None` when the rows
+    /// contradict each other.
+    fn new(matrix: &[Vec<i128>], rhs: &[i128]) -> Option<Self> {
+        let columns = matrix.first().map_or(0, Vec::len);
+        let mut rows = matrix
+            .iter()
+            .zip(rhs)
+            .map(|(row, &value)| {
+                row.iter()
+                    .chain([&value])
+                    .map(|&cell| Rational::from(cell))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut pivots = Vec::new();
+        let mut free = Vec::new();
+        for column in 0..columns {
+            let next = pivots.len();
+            let Some(pivot) = (next..rows.len()).find(|&row| !rows[row][column].is_zero()) else {
+                free.push(column);
+                continue;
+            };
+            rows.swap(next, pivot);
+
+            let scale = rows[next][column];
+            for cell in &mut rows[next] {
+                *cell = *cell / scale;
+            }
+            let pivot_row = rows[next].clone();
+            for (index, row) in rows.iter_mut().enumerate() {
+                let factor = row[column];
+                if index == next || factor.is_zero() {
+                    continue;
+                }
+                for (cell, &pivot) in row.iter_mut().zip(&pivot_row) {
+                    *cell = *cell - factor * pivot;
+                }
+            }
+            pivots.push(column);
+        }
+
+        // Rows left without a pivot read `0 = value`
+        let consistent = rows[pivots.len()..]
+            .iter()
+            .all(|row| row[columns].is_zero());
+        consistent.then_some(Self { rows, pivots, free })
+    }
+
+    /// Every variable's value once the free ones are set to `chosen`.
+    fn solve(&self, chosen: &[Rational]) -> Vec<Rational> {
+        let columns = self.pivots.len() + self.free.len();
+        let mut values = vec![Rational::ZERO; columns];
+        for (&column, &value) in self.free.iter().zip(chosen) {
+            values[column] = value;
+        }
+        for (row, &column) in self.pivots.iter().enumerate() {
+            values[column] = self
+                .free
+                .iter()
+                .fold(self.rows[row][columns], |value, &free| {
+                    value - self.rows[row][free] * values[free]
+                });
+        }
+        values
+    }
+
+    /// Every variable's value and their total cost once the free ones are
+    /// set to `chosen`, `None` unless they are all non negative integers.
+    fn cost(&self, chosen: &[Rational], costs: &[i128]) -> Option<(Vec<i128>, i128)> {
+        let integers = self
+            .solve(chosen)
+            .iter()
+            .map(|value| value.to_integer().filter(|&value| value >= 0))
+            .collect::<Option<Vec<_>>>()?;
+        let cost = integers.iter().zip(costs).map(|(x, cost)| x * cost).sum();
+        Some((integers, cost))
+    }
+
+    /// The cheapest non negative integer solution once the free variables
+    /// in `fixed` are set, solving for the one at index `open` of `free` in
+    /// closed form so huge right hand sides are fine.
+    fn cheapest_along(
+        &self,
+        open: usize,
+        fixed: &[(usize, i128)],
+        costs: &[i128],
+    ) -> Option<(Vec<i128>, i128)> {
+        let columns = self.pivots.len() + self.free.len();
+        let column = self.free[open];
+
+        // Each pivot variable is `value - step * t` for the open variable `t`
+        let lines = self.rows[..self.pivots.len()]
+            .iter()
+            .map(|row| {
+                let value = fixed.iter().fold(row[columns], |value, &(index, chosen)| {
+                    value - row[self.free[index]] * chosen.into()
+                });
+                (value, row[column])
+            })
+            .collect::<Vec<_>>();
+
+        // Over a common denominator `den` that is a whole number when
+        // `step * t = value (mod den)`, which pins `t` to one residue class
+        let congruences = lines
+            .iter()
+            .map(|&(value, step)| {
+                let den = lcm(value.denominator(), step.denominator());
+                let value = value.numerator() * (den / value.denominator());
+                let step = step.numerator() * (den / step.denominator());
+                let (g, inverse, _) = extended_gcd(step, den);
+                if value % g != 0 {
+                    return None;
+                }
+                let modulus = den / g;
+                Some(((value / g % modulus * inverse).rem_euclid(modulus), modulus))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let (residue, period) = crt(congruences)?;
+
+        // Keeping every variable non negative bounds `t` from both sides
+        let mut lowest = 0;
+        let mut highest: Option<i128> = None;
+        for &(value, step) in &lines {
+            match step.cmp(&Rational::ZERO) {
+                Ordering::Greater => {
+                    let bound = (value / step).floor();
+                    highest = Some(highest.map_or(bound, |highest| highest.min(bound)));
+                }
+                Ordering::Less => lowest = lowest.max((value / step).ceil()),
+                Ordering::Equal if value < Rational::ZERO => return None,
+                Ordering::Equal => {}
+            }
+        }
+
+        // The cost is linear in `t`, so the cheapest valid `t` is at an end
+        let slope = self.pivots.iter().zip(&lines).fold(
+            Rational::from(costs[column]),
+            |slope, (&pivot, &(_, step))| slope - Rational::from(costs[pivot]) * step,
+        );
+        let first = residue + Rational::new(lowest - residue, period).ceil() * period;
+        let ends = match highest {
+            Some(highest) => {
+                let last = residue + Rational::new(highest - residue, period).floor() * period;
+                if last < first {
+                    return None;
+                }
+                vec![first, last]
+            }
+            None if slope < Rational::ZERO => return None,
+            None => vec![first],
+        };
+
+        ends.into_iter()
+            .filter_map(|t| {
+                let mut chosen = vec![Rational::ZERO; self.free.len()];
+                chosen[open] = t.into();
+                for &(index, value) in fixed {
+                    chosen[index] = value.into();
+                }
+                self.cost(&chosen, costs)
+            })
+            .min_by_key(|&(_, cost)| cost)
+    }
+}
+
+/// Solves `matrix * x = rhs` with Gaussian elimination.
+pub fn gaussian(matrix: &[Vec<i128>], rhs: &[i128]) -> Solutions {
+    let Some(reduced) = Reduced::new(matrix, rhs) else {
+        return Solutions::None;
+    };
+
+    let particular = reduced.solve(&vec![Rational::ZERO; reduced.free.len()]);
+    if reduced.free.is_empty() {
+        Solutions::Unique(particular)
+    } else {
+        Solutions::Infinite {
+            particular,
+            free: reduced.free,
+        }
+    }
+}
+
+/// Returned by [`min_cost_integer`] when more than one free variable has
+/// nothing bounding it, leaving no finite set of values to search.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Unsupported;
+
+/// The non negative integer solution to `matrix * x = rhs` that minimises
+/// the sum of `costs[i] * x[i]`, along with that cost. `Ok(None)` when there
+/// is no such solution or the cost can fall forever.
+///
+/// One free variable is solved for in closed form, so huge right hand sides
+/// are fine. Every value of any others is tried, up to the bound set by a
+/// row with no negative entries, so all but one of them need such a row.
+pub fn min_cost_integer(
+    matrix: &[Vec<i128>],
+    rhs: &[i128],
+    costs: &[i128],
+) -> Result<Option<(Vec<i128>, i128)>, Unsupported> {
+    let Some(reduced) = Reduced::new(matrix, rhs) else {
+        return Ok(None);
+    };
+    if reduced.free.is_empty() {
+        return Ok(reduced.cost(&[], costs));
+    }
+
+    let bounds = reduced
+        .free
+        .iter()
+        .map(|&column| {
+            matrix
+                .iter()
+                .zip(rhs)
+                .filter(|(row, _)| row[column] > 0 && row.iter().all(|&cell| cell >= 0))
+                .map(|(row, &value)| value.div_euclid(row[column]))
+                .min()
+        })
+        .collect::<Vec<_>>();
+
+    // Leave the variable with the most values to try for the closed form
+    let open = (0..bounds.len())
+        .max_by_key(|&index| bounds[index].unwrap_or(i128::MAX))
+        .unwrap();
+    let searched = (0..bounds.len())
+        .filter(|&index| index != open)
+        .map(|index| bounds[index].map(|bound| (index, bound)))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(Unsupported)?;
+
+    let mut best: Option<(Vec<i128>, i128)> = None;
+    let mut chosen = vec![0; searched.len()];
+    loop {
+        let fixed = searched
+            .iter()
+            .zip(&chosen)
+            .map(|(&(index, _), &value)| (index, value))
+            .collect::<Vec<_>>();
+        if let Some((values, cost)) = reduced.cheapest_along(open, &fixed, costs) {
+            if best.as_ref().is_none_or(|(_, best)| cost < *best) {
+                best = Some((values, cost));
+            }
+        }
+
+        // Count through every combination of the rest like an odometer
+        let Some(position) = (0..chosen.len()).find(|&i| chosen[i] < searched[i].1) else {
+            break;
+        };
+        chosen[position] += 1;
+        chosen[..position].fill(0);
+    }
+
+    Ok(best)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ints(values: &[i128]) -> Vec<Rational> {
+        values.iter().map(|&value| value.into()).collect()
+    }
+
+    #[test]
+    fn rationals() {
+        let half = Rational::new(1, 2);
+        let third = Rational::new(-2, -6);
+
+        assert_eq!(third, Rational::new(1, 3));
+        assert_eq!(Rational::new(3, -6), -half);
+        assert_eq!(half + third, Rational::new(5, 6));
+        assert_eq!(half - third, Rational::new(1, 6));
+        assert_eq!(half * third, Rational::new(1, 6));
+        assert_eq!(half / third, Rational::new(3, 2));
+        assert!(third < half && -half < Rational::ZERO);
+        assert_eq!((half + half).to_integer(), Some(1));
+        assert_eq!(half.to_integer(), None);
+        assert_eq!(Rational::new(-7, 2).floor(), -4);
+        assert_eq!(Rational::new(-7, 2).ceil(), -3);
+        assert_eq!(Rational::from(4).ceil(), 4);
+        assert_eq!(Rational::new(-7, 2).to_string(), "-7/2");
+    }
+
+    #[test]
+    fn determinants() {
+        assert_eq!(determinant(&[vec![94, 22], vec![34, 67]]), 5550);
+        assert_eq!(
+            determinant(&[vec![0, 2, 1], vec![3, -1, 4], vec![2, 5, 6]]),
+            -3
+        );
+        assert_eq!(determinant(&[vec![1, 2], vec![2, 4]]), 0);
+
+        assert_eq!(
+            cramer(&[vec![94, 22], vec![34, 67]], &[8400, 5400]),
+            Some(ints(&[80, 40]))
+        );
+        assert_eq!(
+            cramer(&[vec![2, 0], vec![0, 4]], &[1, 1]),
+            Some(vec![Rational::new(1, 2), Rational::new(1, 4)])
+        );
+        assert_eq!(cramer(&[vec![1, 2], vec![2, 4]], &[1, 2]), None);
+    }
+
+    #[test]
+    fn elimination() {
+        let matrix = [vec![0, 2, 1], vec![3, -1, 4], vec![2, 5, 6]];
+        let rhs = [5, 6, 7];
+        let Solutions::Unique(solution) = gaussian(&matrix, &rhs) else {
+            panic!("expected a unique solution");
+        };
+        assert_eq!(Some(solution), cramer(&matrix, &rhs));
+
+        assert_eq!(
+            gaussian(&[vec![1, 1], vec![2, 2]], &[1, 3]),
+            Solutions::None
+        );
+        assert_eq!(
+            gaussian(&[vec![1, 1, 0], vec![0, 1, 1]], &[3, 5]),
+            Solutions::Infinite {
+                particular: ints(&[-2, 5, 0]),
+                free: vec![2],
+            }
+        );
+    }
+
+    #[test]
+    fn cheapest() {
+        assert_eq!(
+            min_cost_integer(&[vec![94, 22], vec![34, 67]], &[8400, 5400], &[3, 1]),
+            Ok(Some((vec![80, 40], 280)))
+        );
+        assert_eq!(
+            min_cost_integer(&[vec![26, 67], vec![66, 21]], &[12748, 12176], &[3, 1]),
+            Ok(None)
+        );
+
+        // Parallel buttons leave one free variable
+        let matrix = [vec![4, 1], vec![4, 1]];
+        assert_eq!(
+            min_cost_integer(&matrix, &[10, 10], &[3, 1]),
+            Ok(Some((vec![2, 2], 8)))
+        );
+        assert_eq!(
+            min_cost_integer(&matrix, &[10, 10], &[5, 1]),
+            Ok(Some((vec![0, 10], 10)))
+        );
+        assert_eq!(min_cost_integer(&matrix, &[10, 11], &[3, 1]), Ok(None));
+
+        // Far away targets are solved without trying every press count
+        let far = 10000000000007;
+        assert_eq!(
+            min_cost_integer(&[vec![2, 3], vec![2, 3]], &[far, far], &[3, 1]),
+            Ok(Some((vec![1, 3333333333335], 3333333333338)))
+        );
+        assert_eq!(
+            min_cost_integer(&[vec![2, 4], vec![2, 4]], &[far, far], &[3, 1]),
+            Ok(None)
+        );
+
+        for (a, b, target) in
+            (1..6).flat_map(|a| (1..6).flat_map(move |b| (0..40).map(move |t| (a, b, t))))
+        {
+            let brute = (0..=target / a)
+                .flat_map(|x| (0..=target / b).map(move |y| (x, y)))
+                .filter(|(x, y)| a * x + b * y == target)
+                .map(|(x, y)| 3 * x + y)
+                .min();
+            let matrix = [vec![a, b], vec![2 * a, 2 * b]];
+            let cheapest = min_cost_integer(&matrix, &[target, 2 * target], &[3, 1]);
+            assert_eq!(cheapest.map(|found| found.map(|(_, cost)| cost)), Ok(brute));
+        }
+
+        // Negative entries leave the free variable unbounded above, which
+        // is only fine while it costs more the higher it goes
+        let matrix = [vec![1, -1]];
+        assert_eq!(
+            min_cost_integer(&matrix, &[3], &[1, 1]),
+            Ok(Some((vec![3, 0], 3)))
+        );
+        assert_eq!(min_cost_integer(&matrix, &[3], &[1, -2]), Ok(None));
+
+        // Two free variables, the cheapest of every way to make 3
+        assert_eq!(
+            min_cost_integer(&[vec![1, 1, 1]], &[3], &[1, 2, 2]),
+            Ok(Some((vec![3, 0, 0], 3)))
+        );
+        assert_eq!(
+            min_cost_integer(&[vec![1, 1, 1]], &[3], &[3, 2, 1])
+                .map(|found| found.map(|(_, cost)| cost)),
+            Ok(Some(3))
+        );
+        let brute = (0..8)
+            .flat_map(|a| (0..8).flat_map(move |b| (0..8).map(move |c| [a, b, c])))
+            .flat_map(|x| (0..8).map(move |d| [x[0], x[1], x[2], d]))
+            .filter(|x| x[0] + 2 * x[1] + x[2] == 7 && x[1] + 3 * x[3] == 4)
+            .map(|x| 5 * x[0] + x[1] + 2 * x[2] + x[3])
+            .min();
+        assert_eq!(
+            min_cost_integer(
+                &[vec![1, 2, 1, 0], vec![0, 1, 0, 3]],
+                &[7, 4],
+                &[5, 1, 2, 1]
+            )
+            .map(|found| found.map(|(_, cost)| cost)),
+            Ok(brute)
+        );
+
+        // Nothing caps either free variable of `x0 - x1 - x2 = 1`
+        assert_eq!(
+            min_cost_integer(&[vec![1, -1, -1]], &[1], &[1, 1, 1]),
+            Err(Unsupported)
+        );
+
+        let brute = (0..10)
+            .flat_map(|a| (0..10).map(move |b| (a, b)))
+            .flat_map(|(a, b)| (0..10).map(move |c| [a, b, c]))
+            .filter(|x| x[0] + x[1] == 6 && x[1] + 2 * x[2] == 8)
+            .map(|x| (x.to_vec(), 2 * x[0] + 3 * x[1] + x[2]))
+            .min_by_key(|(_, cost)| *cost);
+        assert_eq!(
+            min_cost_integer(&[vec![1, 1, 0], vec![0, 1, 2]], &[6, 8], &[2, 3, 1]),
+            Ok(brute)
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Number theory for puzzles about cycles lining up. Everything works on
+//! `i128`, and products are taken before reducing so moduli should fit in
+//! an `i64`.
+
+/// The greatest common divisor, never negative.
+pub fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, never negative. `0` when either is `0`.
+pub fn lcm(a: i128, b: i128) -> i128 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// The greatest common divisor of every number, `0` when there are none.
+pub fn gcd_all(nums: impl IntoIterator<Item = i128>) -> i128 {
+    nums.into_iter().fold(0, gcd)
+}
+
+/// The least common multiple of every number, `1` when there are none.
+pub fn lcm_all(nums: impl IntoIterator<Item = i128>) -> i128 {
+    nums.into_iter().fold(1, lcm)
+}
+
+/// `(g, x, y)` where `g` is the gcd of `a` and `b` and `a * x + b * y = g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` where `a * x` is `1` modulo `modulus`, `None`
+/// when `a` and `modulus` share a factor.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// `base` to the power of `exp` modulo `modulus`, in `0..modulus`.
+pub fn mod_pow(base: i128, mut exp: u64, modulus: i128) -> i128 {
+    let mut base = base.rem_euclid(modulus);
+    let mut result = 1 % modulus;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+
+    result
+}
+
+/// Solves `x = residue (mod modulus)` for every pair at once with the
+/// Chinese remainder theorem. Moduli do not have to be coprime. Returns the
+/// smallest non negative `x` along with the lcm of the moduli, which every
+/// other solution differs from it by a multiple of. `None` when the
+/// congruences contradict each other.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(x, m), (residue, modulus)| {
+            let (g, p, _) = extended_gcd(m, modulus);
+            let difference = residue - x;
+            if difference % g != 0 {
+                return None;
+            }
+
+            // x + m * k lands on the residue when k = p * difference / g
+            // modulo modulus / g
+            let step = modulus / g;
+            let k = (difference / g % step * p).rem_euclid(step);
+            let combined = m * step;
+
+            Some(((x + m * k).rem_euclid(combined), combined))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn divisors() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(gcd_all([24, 36, 60]), 12);
+        assert_eq!(lcm_all([2, 3, 4, 5]), 60);
+        assert_eq!(lcm_all([]), 1);
+
+        for (a, b) in [(240, 46), (-7, 3), (0, 5), (17, -51)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn modular() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(-2, 3, 5), 2);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+        assert_eq!(mod_pow(3, 1_000_000_006, 1_000_000_007), 1);
+    }
+
+    #[test]
+    fn remainders() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(3, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+
+        // Every solution below the lcm found by brute force
+        for a in 1..12 {
+            for b in 1..12 {
+                for ra in 0..a {
+                    for rb in 0..b {
+                        let brute = (0..a * b).find(|x| x % a == ra && x % b == rb);
+                        assert_eq!(crt([(ra, a), (rb, b)]).map(|(x, _)| x), brute);
+                    }
+                }
+            }
+        }
+    }
+}
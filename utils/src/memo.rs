@@ -0,0 +1,178 @@
+//! Caching the results of recursive functions by their arguments.
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+
+use rustc_hash::FxBuildHasher;
+
+/// A [`Memo`] using the fast, non cryptographic hasher from `rustc-hash`.
+/// Worth it for small keys like numbers and positions.
+pub type FastMemo<K, V> = Memo<K, V, FxBuildHasher>;
+
+/// How well a [`Memo`] has been doing.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+}
+
+impl Stats {
+    /// The share of lookups answered from the cache, `0.0` before any.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            total => self.hits as f64 / total as f64,
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate), {} entries",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0,
+            self.entries
+        )
+    }
+}
+
+/// A cache of function results keyed by their arguments.
+///
+/// ```
+/// use utils::memo::Memo;
+///
+/// fn fib(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+///     if n < 2 {
+///         return n;
+///     }
+///     memo.get_or_compute(n, |memo| fib(n - 1, memo) + fib(n - 2, memo))
+/// }
+///
+/// assert_eq!(fib(90, &mut Memo::new()), 2880067194370816120);
+/// ```
+pub struct Memo<K, V, S = RandomState> {
+    cache: HashMap<K, V, S>,
+    hits: u64,
+    misses: u64,
+}
+
+impl<K, V, S: Default> Default for Memo<K, V, S> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::default(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl<K, V> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<K, V, S> Memo<K, V, S>
+where
+    K: Hash + Eq,
+    V: Clone,
+    S: BuildHasher,
+{
+    /// The cached value for `key`, or the result of `compute` which is then
+    /// cached. `compute` is handed the memo back so it can recurse through
+    /// it.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.cache.len(),
+        }
+    }
+
+    /// Forgets every cached value along with the statistics.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ways to climb `n` stairs taking 1, 2 or 3 at a time.
+    fn stairs<S: BuildHasher>(n: u64, memo: &mut Memo<u64, u64, S>) -> u64 {
+        match n {
+            0 => 1,
+            1 | 2 => n,
+            _ => memo.get_or_compute(n, |memo| {
+                stairs(n - 1, memo) + stairs(n - 2, memo) + stairs(n - 3, memo)
+            }),
+        }
+    }
+
+    #[test]
+    fn recursion() {
+        let mut memo = Memo::new();
+        assert_eq!(stairs(4, &mut memo), 7);
+        assert_eq!(stairs(10, &mut memo), 274);
+
+        // Every n from 3 to 10 is computed exactly once
+        let stats = memo.stats();
+        assert_eq!(stats.misses, 8);
+        assert_eq!(stats.entries, 8);
+        assert_eq!(memo.get(&4), Some(&7));
+        assert_eq!(memo.get(&11), None);
+
+        memo.clear();
+        assert_eq!(memo.stats(), Stats::default());
+    }
+
+    #[test]
+    fn fast() {
+        let mut memo = FastMemo::default();
+        let mut plain = Memo::new();
+
+        assert_eq!(stairs(60, &mut memo), stairs(60, &mut plain));
+        assert_eq!(memo.stats(), plain.stats());
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats {
+            hits: 3,
+            misses: 1,
+            entries: 1,
+        };
+
+        assert_eq!(stats.hit_rate(), 0.75);
+        assert_eq!(Stats::default().hit_rate(), 0.0);
+        assert_eq!(
+            stats.to_string(),
+            "3 hits, 1 misses (75.0% hit rate), 1 entries"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::{Direction, Position};
+
+/// A point on a 2D plane where `y` grows downwards, matching how puzzle
+/// grids are read.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl Point2<i64> {
+    /// The point one step away in `dir`.
+    pub fn step(self, dir: Direction) -> Self {
+        self + dir.offset()
+    }
+
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The row major index of this point in a `width` by `height` grid,
+    /// `None` when it falls outside of it.
+    pub fn to_index(self, width: usize, height: usize) -> Option<usize> {
+        let x = usize::try_from(self.x).ok().filter(|&x| x < width)?;
+        let y = usize::try_from(self.y).ok().filter(|&y| y < height)?;
+
+        Some(y * width + x)
+    }
+}
+
+impl From<Direction> for Point2<i64> {
+    fn from(dir: Direction) -> Self {
+        dir.offset()
+    }
+}
+
+impl From<Position> for Point2<i64> {
+    fn from(position: Position) -> Self {
+        Self::new(position.0 as i64, position.1 as i64)
+    }
+}
+
+impl TryFrom<Point2<i64>> for Position {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(point: Point2<i64>) -> Result<Self, Self::Error> {
+        Ok(Position(point.x.try_into()?, point.y.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ops() {
+        let mut point = Point2::new(3i64, -2);
+
+        assert_eq!(point + Point2::new(1, 1), Point2::new(4, -1));
+        assert_eq!(point - Point2::new(1, 1), Point2::new(2, -3));
+        assert_eq!(-point, Point2::new(-3, 2));
+        assert_eq!(point * 3, Point2::new(9, -6));
+
+        point += Point2::new(10, 10);
+        assert_eq!(point, Point2::new(13, 8));
+        point -= Point2::new(13, 8);
+        assert_eq!(point, Point2::default());
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point2::new(1i64, 2);
+        let b = Point2::new(-3, 5);
+
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(b.manhattan(a), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.manhattan(a), 0);
+        assert_eq!(a.chebyshev(a), 0);
+    }
+
+    #[test]
+    fn indices() {
+        assert_eq!(Point2::new(0i64, 0).to_index(3, 2), Some(0));
+        assert_eq!(Point2::new(2i64, 1).to_index(3, 2), Some(5));
+        assert_eq!(Point2::new(3i64, 0).to_index(3, 2), None);
+        assert_eq!(Point2::new(0i64, 2).to_index(3, 2), None);
+        assert_eq!(Point2::new(-1i64, 0).to_index(3, 2), None);
+        assert_eq!(Point2::new(0i64, -1).to_index(3, 2), None);
+    }
+
+    #[test]
+    fn positions() {
+        assert_eq!(Point2::from(Position(4, 7)), Point2::new(4, 7));
+        assert_eq!(Position::try_from(Point2::new(4, 7)), Ok(Position(4, 7)));
+        assert!(Position::try_from(Point2::new(-1, 7)).is_err());
+        assert!(Position::try_from(Point2::new(1, -7)).is_err());
+    }
+
+    #[test]
+    fn directions() {
+        for dir in Direction::ALL {
+            let offset = Point2::from(dir);
+
+            assert_eq!(Direction::try_from(offset), Ok(dir));
+            assert_eq!(Point2::from(dir.opposite()), -offset);
+            assert_eq!(offset.chebyshev(Point2::default()), 1);
+            assert_eq!(
+                Point2::new(5, 5).step(dir).manhattan(Point2::new(5, 5)),
+                if dir.is_diagonal() { 2 } else { 1 }
+            );
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right().turn_right().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+        }
+
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::UpRight.turn_right(), Direction::DownRight);
+        assert!(Direction::try_from(Point2::new(0, 0)).is_err());
+        assert!(Direction::try_from(Point2::new(2, 0)).is_err());
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{Direction, Grid, Point2, Position};
+
+/// A group of cells in a [`Grid`] connected through their up, down, left
+/// and right neighbours.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Region {
+    pub id: usize,
+    pub cells: Vec<Position>,
+    /// Cell edges that border a cell outside of the region or the grid.
+    pub perimeter: usize,
+    pub corners: usize,
+    /// The top left and bottom right corners of the bounding box, inclusive.
+    pub min: Position,
+    pub max: Position,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The straight sides of the region's outline, holes included. Every
+    /// side ends in a corner so there are as many as there are corners.
+    pub fn sides(&self) -> usize {
+        self.corners
+    }
+}
+
+/// Every region of a [`Grid`] along with which one each cell belongs to.
+pub struct Regions {
+    width: usize,
+    labels: Vec<usize>,
+    pub regions: Vec<Region>,
+}
+
+impl Regions {
+    /// The id of the region `coord` belongs to, `None` when it is outside
+    /// the grid.
+    pub fn id(&self, coord: &Position) -> Option<usize> {
+        (coord.0 < self.width)
+            .then(|| self.labels.get(coord.1 * self.width + coord.0))
+            .flatten()
+            .copied()
+    }
+
+    pub fn region_at(&self, coord: &Position) -> Option<&Region> {
+        self.id(coord).map(|id| &self.regions[id])
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Region> {
+        self.regions.iter()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Claims cells with a stack rather than recursion so large regions
+    /// cannot overflow it. `claim` returns whether a cell was unclaimed.
+    fn fill(
+        &self,
+        start: Position,
+        connected: &impl Fn(&T, &T) -> bool,
+        mut claim: impl FnMut(Position) -> bool,
+    ) -> Vec<Position> {
+        let mut cells = Vec::new();
+        if self.get(&start).is_none() || !claim(start) {
+            return cells;
+        }
+
+        let mut stack = vec![start];
+        while let Some(coord) = stack.pop() {
+            cells.push(coord);
+            let from = self.get(&coord).expect("filled cells to be in the grid");
+            for (next, to) in self.neighbours4(coord) {
+                if connected(from, to) && claim(next) {
+                    stack.push(next);
+                }
+            }
+        }
+
+        cells
+    }
+
+    /// Every cell reachable from `start` by stepping up, down, left or right
+    /// onto cells where `connected(from, to)` holds, `start` included.
+    pub fn flood_fill(&self, start: Position, connected: impl Fn(&T, &T) -> bool) -> Vec<Position> {
+        let mut seen = vec![false; self.width() * self.height()];
+        let width = self.width();
+
+        self.fill(start, &connected, |coord| {
+            !std::mem::replace(&mut seen[coord.1 * width + coord.0], true)
+        })
+    }
+
+    /// Splits the grid into regions of cells where `connected(from, to)`
+    /// holds between neighbours. Ids count up from 0 in the row major order
+    /// of each region's first cell.
+    pub fn regions(&self, connected: impl Fn(&T, &T) -> bool) -> Regions {
+        let (width, height) = (self.width(), self.height());
+        let mut labels = vec![usize::MAX; width * height];
+        let mut regions = Vec::new();
+
+        for index in 0..labels.len() {
+            if labels[index] != usize::MAX {
+                continue;
+            }
+
+            let id = regions.len();
+            let start = Position(index % width, index / width);
+            let cells = self.fill(start, &connected, |coord| {
+                let label = &mut labels[coord.1 * width + coord.0];
+                if *label != usize::MAX {
+                    return false;
+                }
+                *label = id;
+                true
+            });
+
+            let inside = |point: Point2<i64>| {
+                point
+                    .to_index(width, height)
+                    .is_some_and(|index| labels[index] == id)
+            };
+
+            let mut perimeter = 0;
+            let mut corners = 0;
+            for &coord in &cells {
+                let point = Point2::from(coord);
+                for dir in Direction::CARDINAL {
+                    let side = inside(point.step(dir));
+                    let next = inside(point.step(dir.turn_right()));
+                    let diagonal = inside(point.step(dir).step(dir.turn_right()));
+
+                    perimeter += usize::from(!side);
+                    // Either both sides are outside, an outer corner, or
+                    // both are inside with the cell between them outside,
+                    // an inner one.
+                    // AA    AB
+                    // AB    BB
+                    corners += usize::from(!side && !next || side && next && !diagonal);
+                }
+            }
+
+            let min = Position(
+                cells.iter().map(|c| c.0).min().unwrap(),
+                cells.iter().map(|c| c.1).min().unwrap(),
+            );
+            let max = Position(
+                cells.iter().map(|c| c.0).max().unwrap(),
+                cells.iter().map(|c| c.1).max().unwrap(),
+            );
+
+            regions.push(Region {
+                id,
+                cells,
+                perimeter,
+                corners,
+                min,
+                max,
+            });
+        }
+
+        Regions {
+            width,
+            labels,
+            regions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GARDEN: &str = "AAAA
+BBCD
+BBCC
+EEEC";
+
+    #[test]
+    fn regions() {
+        let grid = Grid::<char>::from(GARDEN);
+        let regions = grid.regions(|a, b| a == b);
+
+        let summary = regions
+            .iter()
+            .map(|region| {
+                let plant = grid.get(&region.cells[0]).unwrap();
+                (*plant, region.area(), region.perimeter, region.sides())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ]
+        );
+
+        let c = regions.region_at(&Position(2, 1)).unwrap();
+        assert_eq!(c.id, 2);
+        assert_eq!((c.min, c.max), (Position(2, 1), Position(3, 3)));
+        assert_eq!(regions.id(&Position(3, 1)), Some(3));
+        assert_eq!(regions.id(&Position(4, 0)), None);
+        assert_eq!(regions.id(&Position(0, 4)), None);
+    }
+
+    #[test]
+    fn holes() {
+        // The outer region has 4 outer sides and 4 around each hole.
+        let grid = Grid::<char>::from("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA");
+        let regions = grid.regions(|a, b| a == b);
+
+        assert_eq!(regions.regions.len(), 3);
+        assert_eq!(regions.regions[0].area(), 28);
+        assert_eq!(regions.regions[0].sides(), 12);
+        assert_eq!(regions.regions[0].perimeter, 40);
+    }
+
+    #[test]
+    fn flood_fill() {
+        let grid = Grid::<char>::from(GARDEN);
+
+        let mut cells = grid.flood_fill(Position(3, 3), |a, b| a == b);
+        cells.sort();
+        assert_eq!(
+            cells,
+            vec![
+                Position(2, 1),
+                Position(2, 2),
+                Position(3, 2),
+                Position(3, 3)
+            ]
+        );
+        assert_eq!(grid.flood_fill(Position(0, 0), |_, _| true).len(), 16);
+        assert!(grid.flood_fill(Position(4, 0), |_, _| true).is_empty());
+    }
+
+    #[test]
+    fn large_region() {
+        let grid = Grid::new(500, 500, '.');
+
+        assert_eq!(
+            grid.flood_fill(Position(0, 0), |a, b| a == b).len(),
+            250_000
+        );
+        assert_eq!(grid.regions(|a, b| a == b).regions[0].perimeter, 2000);
+    }
+}
@@ -0,0 +1,286 @@
+//! Searches over any state space described by a successor function.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::{Grid, Position};
+
+/// Every state reached from the start, how far away it is and which state
+/// it was reached from.
+pub struct Search<S> {
+    pub distances: HashMap<S, u64>,
+    pub predecessors: HashMap<S, S>,
+}
+
+impl<S: Hash + Eq + Clone> Search<S> {
+    fn new(start: S) -> Self {
+        Self {
+            distances: HashMap::from([(start, 0)]),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        self.distances.get(state).copied()
+    }
+
+    /// The states from the start up to and including `goal`, `None` when
+    /// `goal` was never reached.
+    pub fn path_to(&self, goal: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(goal) {
+            return None;
+        }
+
+        let mut path = vec![goal.clone()];
+        let mut current = goal;
+        while let Some(previous) = self.predecessors.get(current) {
+            path.push(previous.clone());
+            current = previous;
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+/// Breadth first search, so distances are the fewest steps to each state.
+pub fn bfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, distance)) = queue.pop_front() {
+        for next in successors(&state) {
+            if search.distances.contains_key(&next) {
+                continue;
+            }
+            search.distances.insert(next.clone(), distance + 1);
+            search.predecessors.insert(next.clone(), state.clone());
+            queue.push_back((next, distance + 1));
+        }
+    }
+
+    search
+}
+
+/// Depth first search. Distances are depths in the search tree rather than
+/// shortest distances, which makes this mostly useful for reachability.
+pub fn dfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start.clone());
+    let mut stack = vec![(start, 0)];
+
+    while let Some((state, depth)) = stack.pop() {
+        for next in successors(&state) {
+            if search.distances.contains_key(&next) {
+                continue;
+            }
+            search.distances.insert(next.clone(), depth + 1);
+            search.predecessors.insert(next.clone(), state.clone());
+            stack.push((next, depth + 1));
+        }
+    }
+
+    search
+}
+
+/// Shortest distances when each step has a cost.
+pub fn dijkstra<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut search = Search::new(start.clone());
+    // States are kept out of the heap so they do not need to be `Ord`.
+    let mut states = vec![start];
+    let mut heap = BinaryHeap::from([Reverse((0, 0))]);
+
+    while let Some(Reverse((distance, index))) = heap.pop() {
+        let state = states[index].clone();
+        if search.distances[&state] < distance {
+            continue;
+        }
+
+        for (next, cost) in successors(&state) {
+            let next_distance = distance + cost;
+            if search
+                .distances
+                .get(&next)
+                .is_some_and(|&known| known <= next_distance)
+            {
+                continue;
+            }
+            search.distances.insert(next.clone(), next_distance);
+            search.predecessors.insert(next.clone(), state.clone());
+            heap.push(Reverse((next_distance, states.len())));
+            states.push(next);
+        }
+    }
+
+    search
+}
+
+/// The cheapest path from `start` to a state where `is_goal` holds, along
+/// with its cost. `heuristic` must never overestimate the remaining cost.
+pub fn astar<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, u64)>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut search = Search::new(start.clone());
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut states = vec![start];
+
+    while let Some(Reverse((_, distance, index))) = heap.pop() {
+        let state = states[index].clone();
+        if search.distances[&state] < distance {
+            continue;
+        }
+        if is_goal(&state) {
+            return search.path_to(&state).map(|path| (path, distance));
+        }
+
+        for (next, cost) in successors(&state) {
+            let next_distance = distance + cost;
+            if search
+                .distances
+                .get(&next)
+                .is_some_and(|&known| known <= next_distance)
+            {
+                continue;
+            }
+            search.distances.insert(next.clone(), next_distance);
+            search.predecessors.insert(next.clone(), state.clone());
+            heap.push(Reverse((
+                next_distance + heuristic(&next),
+                next_distance,
+                states.len(),
+            )));
+            states.push(next);
+        }
+    }
+
+    None
+}
+
+/// Successors for searching a grid by moving up, down, left or right onto
+/// cells where `passable(from, to)` holds.
+pub fn grid_moves<'a, T>(
+    grid: &'a Grid<T>,
+    passable: impl Fn(&T, &T) -> bool + 'a,
+) -> impl FnMut(&Position) -> Vec<Position> + 'a {
+    move |position| {
+        let Some(from) = grid.get(position) else {
+            return Vec::new();
+        };
+
+        grid.neighbours4(*position)
+            .filter(|(_, to)| passable(from, to))
+            .map(|(next, _)| next)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point2;
+
+    const MAZE: &str = "S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+    fn maze() -> (Grid<char>, Position, Position) {
+        let grid = Grid::<char>::from(MAZE);
+        let find = |target| {
+            grid.iter_with_coords()
+                .find(|(_, &ch)| ch == target)
+                .map(|(position, _)| position)
+                .unwrap()
+        };
+        let (start, end) = (find('S'), find('E'));
+
+        (grid, start, end)
+    }
+
+    #[test]
+    fn bfs_on_grid() {
+        let (grid, start, end) = maze();
+        let search = bfs(start, grid_moves(&grid, |_, &to| to != '#'));
+
+        assert_eq!(search.distance(&end), Some(15));
+        assert_eq!(search.distance(&Position(2, 2)), Some(4));
+        assert_eq!(search.distance(&Position(3, 0)), None);
+
+        let path = search.path_to(&end).unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&end));
+        for step in path.windows(2) {
+            assert_eq!(Point2::from(step[0]).manhattan(Point2::from(step[1])), 1);
+            assert_ne!(grid.get(&step[1]), Some(&'#'));
+        }
+        assert_eq!(search.path_to(&Position(3, 0)), None);
+    }
+
+    #[test]
+    fn dfs_reaches_everything() {
+        let (grid, start, _) = maze();
+        let reached = dfs(start, grid_moves(&grid, |_, &to| to != '#'));
+        let open = grid.iter_with_coords().filter(|(_, &ch)| ch != '#').count();
+
+        assert_eq!(reached.distances.len(), open);
+    }
+
+    #[test]
+    fn weighted() {
+        // 0 -> 1 costs 7 directly but 2 through 2 and 3.
+        let edges = |node: &u32| match node {
+            0 => vec![(1, 7), (2, 1)],
+            2 => vec![(3, 0), (1, 5)],
+            3 => vec![(1, 1)],
+            _ => vec![],
+        };
+
+        let search = dijkstra(0, edges);
+        assert_eq!(search.distance(&1), Some(2));
+        assert_eq!(search.path_to(&1), Some(vec![0, 2, 3, 1]));
+
+        assert_eq!(
+            astar(0, edges, |_| 0, |&node| node == 1),
+            Some((vec![0, 2, 3, 1], 2))
+        );
+        assert_eq!(astar(0, edges, |_| 0, |&node| node == 9), None);
+    }
+
+    #[test]
+    fn astar_matches_bfs() {
+        let (grid, start, end) = maze();
+        let mut moves = grid_moves(&grid, |_, &to| to != '#');
+
+        let (path, cost) = astar(
+            start,
+            |position| moves(position).into_iter().map(|next| (next, 1)),
+            |&position| Point2::from(position).manhattan(Point2::from(end)) as u64,
+            |&position| position == end,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 15);
+        assert_eq!(path.len(), 16);
+    }
+}
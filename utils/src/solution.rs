@@ -0,0 +1,140 @@
+use std::any::Any;
+use std::fmt::Display;
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Num(i128),
+    Text(String),
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Num(num) => write!(f, "{num}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_from_num {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Self::Num(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_num!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A single day's puzzle. The input is parsed once and both parts are
+/// solved from the parsed value.
+pub trait Solution {
+    type Parsed;
+
+    fn parse(input: &str) -> Self::Parsed;
+
+    fn part1(parsed: &Self::Parsed) -> Answer;
+
+    fn part2(_parsed: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+/// Object safe version of [`Solution`] so days with different parsed types
+/// can live in the same collection.
+pub trait DynSolution {
+    fn parse(&self, input: &str) -> Box<dyn Any>;
+
+    /// Panics if `parsed` did not come from this solution's `parse`.
+    fn solve(&self, parsed: &dyn Any, part: Part) -> Answer;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution,
+    S::Parsed: 'static,
+{
+    fn parse(&self, input: &str) -> Box<dyn Any> {
+        Box::new(S::parse(input))
+    }
+
+    fn solve(&self, parsed: &dyn Any, part: Part) -> Answer {
+        let parsed = parsed
+            .downcast_ref::<S::Parsed>()
+            .expect("parsed input to belong to this solution");
+
+        match part {
+            Part::One => S::part1(parsed),
+            Part::Two => S::part2(parsed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<u32>;
+
+        fn parse(input: &str) -> Self::Parsed {
+            input.lines().map(|line| line.parse().unwrap()).collect()
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Answer {
+            parsed.iter().sum::<u32>().into()
+        }
+    }
+
+    #[test]
+    fn dyn_solution() {
+        let solution: &dyn DynSolution = &Sum;
+        let parsed = solution.parse("1\n2\n3");
+
+        assert_eq!(solution.solve(parsed.as_ref(), Part::One), Answer::Num(6));
+        assert_eq!(solution.solve(parsed.as_ref(), Part::Two), Answer::Unsolved);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(42u64).to_string(), "42");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+}